@@ -1,3 +1,3 @@
 #!/usr/bin/env sh
 
-cargo clippy --tests --quiet --no-deps -- -D warnings
+cargo clippy --tests --all-features --quiet --no-deps -- -D warnings
@@ -0,0 +1,117 @@
+use crate::{Currency, Money};
+
+impl<C: Currency> Money<C> {
+    /// Splits the amount into `n` parts that are as equal as possible.
+    ///
+    /// The parts always sum to exactly the original amount. Any leftover minor units are handed
+    /// out one at a time to the first parts, so splitting `$10.00` three ways gives
+    /// `[$3.34, $3.33, $3.33]`.
+    ///
+    /// Panics if `n` is zero.
+    pub fn split(self, n: usize) -> Vec<Self> {
+        assert!(n > 0, "cannot split into zero parts");
+
+        let n = n as i128;
+        let value = self.minor_units() as i128;
+        let share = value / n;
+        let leftover = value % n;
+        let step = leftover.signum();
+
+        (0..n)
+            .map(|i| share + if i < leftover.abs() { step } else { 0 })
+            .map(|part| Self::from_minor_units(part as i64))
+            .collect()
+    }
+
+    /// Allocates the amount into parts proportional to `weights`.
+    ///
+    /// The parts always sum to exactly the original amount. Each part first gets its exact share
+    /// rounded toward zero, and then the leftover minor units are handed out one at a time to the
+    /// parts with the largest remainders, breaking ties in favor of earlier parts. For example,
+    /// allocating `$0.05` by weights `[1, 3]` gives `[$0.01, $0.04]`, since the exact shares are
+    /// `$0.0125` and `$0.0375`.
+    ///
+    /// Negative amounts are allocated by magnitude, so every part has the same sign as the
+    /// original amount. Parts with a weight of zero are always zero.
+    ///
+    /// Panics if `weights` is empty or all of the weights are zero.
+    pub fn allocate(self, weights: &[u64]) -> Vec<Self> {
+        let total = weights.iter().map(|&w| w as u128).sum::<u128>();
+        assert!(total > 0, "cannot allocate with no nonzero weights");
+
+        let magnitude = self.unsigned_abs() as u128;
+        let mut parts = weights
+            .iter()
+            .map(|&w| magnitude * w as u128)
+            .map(|exact| (exact / total, exact % total))
+            .collect::<Vec<_>>();
+
+        let allocated = parts.iter().map(|&(part, _)| part).sum::<u128>();
+        let mut by_remainder = (0..parts.len()).collect::<Vec<_>>();
+        // stable sort, so ties keep their original order
+        by_remainder.sort_by(|&a, &b| parts[b].1.cmp(&parts[a].1));
+
+        for &i in by_remainder.iter().take((magnitude - allocated) as usize) {
+            parts[i].0 += 1;
+        }
+
+        parts
+            .into_iter()
+            .map(|(part, _)| {
+                let part = part as i128;
+                Self::from_minor_units(if self.is_negative() { -part } else { part } as i64)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Dollars;
+
+    fn cents(values: Vec<Dollars>) -> Vec<i64> {
+        values.into_iter().map(|d| d.in_cents()).collect()
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(cents(Dollars::from(1000).split(3)), [334, 333, 333]);
+        assert_eq!(cents(Dollars::from(-1000).split(3)), [-334, -333, -333]);
+        assert_eq!(cents(Dollars::from(2).split(4)), [1, 1, 0, 0]);
+        assert_eq!(cents(Dollars::from(900).split(3)), [300, 300, 300]);
+        assert_eq!(cents(Dollars::from(i64::MIN).split(1)), [i64::MIN]);
+    }
+
+    #[test]
+    fn allocate() {
+        assert_eq!(cents(Dollars::from(5).allocate(&[1, 3])), [1, 4]);
+        assert_eq!(cents(Dollars::from(5).allocate(&[3, 7])), [2, 3]);
+        assert_eq!(cents(Dollars::from(100).allocate(&[1, 1, 1])), [34, 33, 33]);
+        assert_eq!(cents(Dollars::from(100).allocate(&[1, 0, 1])), [50, 0, 50]);
+        assert_eq!(cents(Dollars::from(-1001).allocate(&[50, 30, 20])), [
+            -501, -300, -200
+        ]);
+        assert_eq!(cents(Dollars::from(7).allocate(&[1, 2, 2])), [1, 3, 3]);
+    }
+
+    #[test]
+    fn allocate_sums_to_original() {
+        let values = [0, 1, 99, 1000, -12345, i64::MAX, i64::MIN];
+        let weights: [&[u64]; 4] = [&[1], &[1, 2, 3], &[u64::MAX, 1, u64::MAX], &[7, 0, 13, 5]];
+
+        for value in values {
+            for weights in weights {
+                let parts = Dollars::from(value).allocate(weights);
+                let sum = parts.iter().map(|d| d.in_cents() as i128).sum::<i128>();
+
+                assert_eq!(sum, value as i128, "allocating {} by {:?}", value, weights);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn allocate_with_zero_weights() {
+        Dollars::from(100).allocate(&[0, 0]);
+    }
+}
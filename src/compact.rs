@@ -0,0 +1,368 @@
+use std::fmt::Write;
+
+use crate::{rounding, Dollars, ParseError, ParseErrorKind, RoundingMode};
+
+/// The suffixes for each power of a thousand dollars, starting from a single dollar.
+const SUFFIXES: [&str; 5] = ["", "K", "M", "B", "T"];
+
+/// The number of fractional digits that can affect rounding a compact value to a cent: two for
+/// cents, three for each step up to the largest suffix, and one more for the rounding digit.
+const MAX_FRACTION_DIGITS: u32 = 2 + 3 * (SUFFIXES.len() as u32 - 1) + 1;
+
+/// Options controlling how a [`Dollars`] value is formatted by
+/// [`format_compact`](Dollars::format_compact).
+///
+/// ```
+/// # use dollars::{CompactOptions, Dollars, RoundingMode};
+/// let options = CompactOptions::new()
+///     .significant_digits(2)
+///     .rounding_mode(RoundingMode::Floor);
+///
+/// assert_eq!(Dollars::from(129_999).format_compact(options), "$1.2K");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CompactOptions {
+    significant_digits: u8,
+    rounding_mode: RoundingMode,
+}
+
+impl CompactOptions {
+    /// The default options, with three significant digits, rounding half away from zero.
+    pub const DEFAULT: Self = Self::new();
+
+    /// The default options, with three significant digits, rounding half away from zero.
+    pub const fn new() -> Self {
+        Self {
+            significant_digits: 3,
+            rounding_mode: RoundingMode::HalfAwayFromZero,
+        }
+    }
+
+    /// Sets the number of significant digits to show. Defaults to 3.
+    ///
+    /// Values are never shown more precisely than to the cent, so small values may have fewer
+    /// significant digits than this.
+    ///
+    /// Panics if `significant_digits` is zero.
+    pub const fn significant_digits(mut self, significant_digits: u8) -> Self {
+        assert!(significant_digits > 0, "significant digits must be nonzero");
+        self.significant_digits = significant_digits;
+        self
+    }
+
+    /// Sets how the value is rounded to its significant digits. Defaults to
+    /// [`RoundingMode::HalfAwayFromZero`].
+    pub const fn rounding_mode(mut self, rounding_mode: RoundingMode) -> Self {
+        self.rounding_mode = rounding_mode;
+        self
+    }
+}
+
+impl Default for CompactOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dollars {
+    /// Formats the value compactly, with a suffix for thousands (`K`), millions (`M`), billions
+    /// (`B`), or trillions (`T`) of dollars, like `$1.2K` or `-$3.45M`.
+    ///
+    /// The value is rounded to the configured number of significant digits, but never finer than
+    /// a cent, and trailing zeros after the decimal point are dropped. Values under a thousand dollars have no suffix, and
+    /// values that round up to the next suffix use it, so `$999,999` is `$1M` rather than
+    /// `$1000K`.
+    ///
+    /// ```
+    /// # use dollars::{CompactOptions, Dollars};
+    /// assert_eq!(Dollars::from(123_456).format_compact(CompactOptions::DEFAULT), "$1.23K");
+    /// assert_eq!(Dollars::from(-340_000_000).format_compact(CompactOptions::DEFAULT), "-$3.4M");
+    /// assert_eq!(Dollars::from(1999).format_compact(CompactOptions::DEFAULT), "$20");
+    /// ```
+    pub fn format_compact(self, options: CompactOptions) -> String {
+        let cent_value = self.in_cents() as i128;
+        let magnitude = cent_value.unsigned_abs();
+        let significant_digits = options.significant_digits as u32;
+
+        let cents_per = |tier: usize| 100 * 1000_u128.pow(tier as u32);
+        let mut tier = (1..SUFFIXES.len())
+            .rev()
+            .find(|&tier| magnitude >= cents_per(tier))
+            .unwrap_or(0);
+
+        loop {
+            let unit = cents_per(tier);
+            // the number of digits before the decimal point, counting from the first nonzero
+            // one, so it's zero or negative for values under one unit, like -1 for $0.04
+            let whole_digits = magnitude
+                .checked_ilog10()
+                .map_or(1, |log| log as i32 + 1 - unit.ilog10() as i32);
+
+            // the number of decimal places to round to, which is negative when rounding to tens,
+            // hundreds, and so on, but never finer than a cent
+            let precision = (significant_digits as i32 - whole_digits).min(2 + 3 * tier as i32);
+            let decimals = precision.max(0) as u32;
+            let step = if precision >= 0 {
+                unit / 10_u128.pow(decimals)
+            } else {
+                unit * 10_u128.pow(precision.unsigned_abs())
+            };
+
+            let rounded = rounding::div_round(cent_value, step as i128, options.rounding_mode);
+            let shown = rounded.unsigned_abs() * (step * 10_u128.pow(decimals) / unit);
+
+            if shown >= 1000 * 10_u128.pow(decimals) && tier + 1 < SUFFIXES.len() {
+                tier += 1;
+                continue;
+            }
+
+            let mut s = String::new();
+
+            if rounded < 0 {
+                s.push('-');
+            }
+
+            let scale = 10_u128.pow(decimals);
+            write!(s, "${}", shown / scale).unwrap();
+
+            if decimals > 0 {
+                let fraction = format!("{:0width$}", shown % scale, width = decimals as usize);
+                let fraction = fraction.trim_end_matches('0');
+
+                if !fraction.is_empty() {
+                    write!(s, ".{}", fraction).unwrap();
+                }
+            }
+
+            s.push_str(SUFFIXES[tier]);
+            return s;
+        }
+    }
+
+    /// Parses a value in the compact form produced by [`format_compact`](Dollars::format_compact),
+    /// like `$1.2K`, rounding it to a whole number of cents according to `mode`.
+    ///
+    /// This is lenient, since it's meant for values typed in by hand: the sign, `$`, decimal
+    /// point, and suffix are all optional, suffixes can be upper or lower case, commas can
+    /// separate digits, and there can be whitespace around the value and before the suffix. Any
+    /// number of digits can follow the decimal point.
+    ///
+    /// ```
+    /// # use dollars::{Dollars, RoundingMode};
+    /// let parse = |s| Dollars::parse_compact(s, RoundingMode::HalfEven);
+    ///
+    /// assert_eq!(parse("$1.2k"), Ok(Dollars::from(120_000)));
+    /// assert_eq!(parse(" -3.4567 M "), Ok(Dollars::from(-345_670_000)));
+    /// assert_eq!(parse("12.345"), Ok(Dollars::from(1234)));
+    /// ```
+    pub fn parse_compact(s: &str, mode: RoundingMode) -> Result<Self, ParseError> {
+        let bytes = s.as_bytes();
+        let mut i = bytes.iter().take_while(|b| b.is_ascii_whitespace()).count();
+
+        let is_negative = match bytes.get(i) {
+            Some(b'-') => {
+                i += 1;
+                true
+            },
+
+            Some(b'+') => {
+                i += 1;
+                false
+            },
+
+            _ => false,
+        };
+
+        if bytes.get(i) == Some(&b'$') {
+            i += 1;
+        }
+
+        // the digits are accumulated into a single mantissa, along with the number of them after
+        // the decimal point. Fractional digits past MAX_FRACTION_DIGITS can't change which cents
+        // the value lies between, or whether it's exactly halfway, so they only matter for
+        // whether or not the value is exact
+        let digits_start = i;
+        let overflow = ParseError::new(ParseErrorKind::Overflow, digits_start);
+        let mut mantissa = 0_i128;
+        let mut has_digits = false;
+        let mut fraction_digits = None;
+        let mut is_inexact = false;
+
+        while let Some(&b) = bytes.get(i) {
+            match b {
+                b'0'..=b'9' if fraction_digits == Some(MAX_FRACTION_DIGITS) => {
+                    is_inexact |= b != b'0';
+                },
+                b'0'..=b'9' => {
+                    mantissa = mantissa
+                        .checked_mul(10)
+                        .and_then(|mantissa| mantissa.checked_add((b - b'0') as i128))
+                        .ok_or(overflow)?;
+                    has_digits = true;
+                    fraction_digits = fraction_digits.map(|n: u32| n + 1);
+                },
+                b',' if has_digits && fraction_digits.is_none() => {},
+                b'.' if fraction_digits.is_none() => fraction_digits = Some(0),
+                b'.' => return Err(ParseError::new(ParseErrorKind::ExtraDecimalPoint, i)),
+                _ => break,
+            }
+
+            i += 1;
+        }
+
+        if !has_digits {
+            return Err(ParseError::new(ParseErrorKind::MissingDigits, i));
+        }
+
+        i += bytes[i..]
+            .iter()
+            .take_while(|b| b.is_ascii_whitespace())
+            .count();
+
+        let tier = match bytes.get(i).map(u8::to_ascii_uppercase) {
+            Some(b'K') => 1,
+            Some(b'M') => 2,
+            Some(b'B') => 3,
+            Some(b'T') => 4,
+            _ => 0,
+        };
+
+        if tier > 0 {
+            i += 1;
+        }
+
+        i += bytes[i..]
+            .iter()
+            .take_while(|b| b.is_ascii_whitespace())
+            .count();
+
+        if i < bytes.len() {
+            return Err(ParseError::new(ParseErrorKind::TrailingCharacters, i));
+        }
+
+        // a nonzero remainder is folded in as one more nonzero digit, which rounds the same way
+        let (mantissa, fraction_digits) = if is_inexact {
+            let mantissa = mantissa.checked_mul(10).ok_or(overflow)? + 1;
+            (mantissa, MAX_FRACTION_DIGITS + 1)
+        } else {
+            (mantissa, fraction_digits.unwrap_or(0))
+        };
+
+        let numerator = mantissa
+            .checked_mul(100 * 1000_i128.pow(tier))
+            .map(|numerator| if is_negative { -numerator } else { numerator })
+            .ok_or(overflow)?;
+        let denominator = 10_i128.pow(fraction_digits);
+
+        i64::try_from(rounding::div_round(numerator, denominator, mode))
+            .map(Self::from)
+            .map_err(|_| overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(cents: i64, options: CompactOptions) -> String {
+        Dollars::from(cents).format_compact(options)
+    }
+
+    fn parse(s: &str) -> Result<i64, (ParseErrorKind, usize)> {
+        Dollars::parse_compact(s, RoundingMode::HalfEven)
+            .map(|value| value.in_cents())
+            .map_err(|error| (error.kind(), error.position()))
+    }
+
+    #[test]
+    fn suffixes() {
+        let options = CompactOptions::DEFAULT;
+
+        assert_eq!(format(0, options), "$0");
+        assert_eq!(format(5, options), "$0.05");
+        assert_eq!(format(-123, options), "-$1.23");
+        assert_eq!(format(99_999, options), "$1K");
+        assert_eq!(format(120_000, options), "$1.2K");
+        assert_eq!(format(12_345_600, options), "$123K");
+        assert_eq!(format(340_000_000, options), "$3.4M");
+        assert_eq!(format(105_000_000_000, options), "$1.05B");
+        assert_eq!(format(99_950_000_000_000, options), "$1T");
+        assert_eq!(format(i64::MAX, options), "$92200T");
+        assert_eq!(format(i64::MIN, options), "-$92200T");
+    }
+
+    #[test]
+    fn significant_digits_and_rounding() {
+        let options = CompactOptions::new().significant_digits(2);
+
+        assert_eq!(format(125_000, options), "$1.3K");
+        assert_eq!(
+            format(125_000, options.rounding_mode(RoundingMode::HalfEven)),
+            "$1.2K"
+        );
+        assert_eq!(
+            format(-121_000, options.rounding_mode(RoundingMode::Floor)),
+            "-$1.3K"
+        );
+        assert_eq!(format(95_000, options), "$950");
+        assert_eq!(format(95_000, options.significant_digits(1)), "$1K");
+        assert_eq!(format(-4, options.significant_digits(1)), "-$0.04");
+        assert_eq!(format(45, options.significant_digits(1)), "$0.5");
+        assert_eq!(format(12, options.significant_digits(1)), "$0.1");
+        assert_eq!(format(4, options), "$0.04");
+        assert_eq!(format(96, options.significant_digits(1)), "$1");
+        assert_eq!(format(0, options), "$0");
+
+        let options = CompactOptions::new().significant_digits(10);
+        assert_eq!(format(123_456_789, options), "$1.23456789M");
+        assert_eq!(format(-1_999, options), "-$19.99");
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(parse("$1.2k"), Ok(120_000));
+        assert_eq!(parse("1.2K"), Ok(120_000));
+        assert_eq!(parse("  +$3.4 M "), Ok(340_000_000));
+        assert_eq!(parse("-1,234"), Ok(-123_400));
+        assert_eq!(parse("$.5b"), Ok(50_000_000_000));
+        assert_eq!(parse("2t"), Ok(200_000_000_000_000));
+        assert_eq!(parse("0.005"), Ok(0));
+        assert_eq!(parse("0.015"), Ok(2));
+        assert_eq!(parse("1.234567k"), Ok(123_457));
+        assert_eq!(parse("0.00000000000000000000000000000000000000001"), Ok(0));
+        assert_eq!(
+            Dollars::parse_compact(
+                "0.00000000000000000000000000000000000000001",
+                RoundingMode::Ceil
+            )
+            .map(|value| value.in_cents()),
+            Ok(1)
+        );
+        assert_eq!(parse("0.0050000000000000000000001"), Ok(1));
+        assert_eq!(parse("0.00500000000000000000000000"), Ok(0));
+        assert_eq!(
+            parse("-1.00000000000000000000009t"),
+            Ok(-100_000_000_000_000)
+        );
+
+        for cents in [0, 5, -123, 120_000, 105_000_000_000, -340_000_000] {
+            let s = format(cents, CompactOptions::DEFAULT);
+            assert_eq!(parse(&s), Ok(cents), "{}", s);
+        }
+    }
+
+    #[test]
+    fn parsing_errors() {
+        assert_eq!(parse(""), Err((ParseErrorKind::MissingDigits, 0)));
+        assert_eq!(parse("$k"), Err((ParseErrorKind::MissingDigits, 1)));
+        assert_eq!(parse(",5"), Err((ParseErrorKind::MissingDigits, 0)));
+        assert_eq!(parse("1.2.3"), Err((ParseErrorKind::ExtraDecimalPoint, 3)));
+        assert_eq!(parse("1.2kk"), Err((ParseErrorKind::TrailingCharacters, 4)));
+        assert_eq!(parse("1.2x"), Err((ParseErrorKind::TrailingCharacters, 3)));
+        assert_eq!(parse("$100000T"), Err((ParseErrorKind::Overflow, 1)));
+        assert_eq!(
+            parse("-92233720368547758.095"),
+            Err((ParseErrorKind::Overflow, 1))
+        );
+    }
+}
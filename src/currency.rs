@@ -0,0 +1,179 @@
+//! ISO 4217 currencies, for use with [`Money`](crate::Money).
+//!
+//! Each currency is a zero-sized marker type implementing [`Currency`], so that amounts in
+//! different currencies are different types, and mixing them up is a compile error. The same
+//! information is also available at runtime through [`CurrencyInfo`].
+
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::Hash;
+
+/// A currency, identified at the type level.
+///
+/// This is implemented by the marker types in this module, like [`Usd`] and [`Eur`].
+pub trait Currency: Copy + Debug + Default + Eq + Hash + Ord + 'static {
+    /// The ISO 4217 details of the currency.
+    const INFO: CurrencyInfo;
+}
+
+/// The ISO 4217 details of a currency.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CurrencyInfo {
+    code: &'static str,
+    numeric: u16,
+    symbol: &'static str,
+    exponent: u32,
+}
+
+impl CurrencyInfo {
+    /// Looks up a currency in the table by its three-letter code, ignoring case.
+    pub fn from_code(code: &str) -> Option<Self> {
+        ALL.iter()
+            .find(|info| info.code.eq_ignore_ascii_case(code))
+            .copied()
+    }
+
+    /// Looks up a currency in the table by its numeric code.
+    pub fn from_numeric(numeric: u16) -> Option<Self> {
+        ALL.iter().find(|info| info.numeric == numeric).copied()
+    }
+
+    /// The three-letter code, like `USD`.
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// The numeric code, like 840 for `USD`.
+    pub const fn numeric(&self) -> u16 {
+        self.numeric
+    }
+
+    /// The symbol used when formatting amounts, like `$` for `USD`.
+    ///
+    /// Symbols that are shared between several currencies are disambiguated where that's
+    /// conventional, like `CA$` for `CAD`.
+    pub const fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// The number of digits in the minor unit, like 2 for `USD` (cents) or 0 for `JPY`.
+    pub const fn exponent(&self) -> u32 {
+        self.exponent
+    }
+}
+
+impl Display for CurrencyInfo {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self.code, f)
+    }
+}
+
+macro_rules! currencies {
+    ($($name:ident => ($code:literal, $numeric:literal, $symbol:literal, $exponent:literal, $doc:literal),)*) => {
+        $(
+            #[doc = concat!("The ", $doc, " (", $code, ").")]
+            #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            pub struct $name;
+
+            impl Currency for $name {
+                const INFO: CurrencyInfo = CurrencyInfo {
+                    code: $code,
+                    numeric: $numeric,
+                    symbol: $symbol,
+                    exponent: $exponent,
+                };
+            }
+        )*
+
+        /// Every currency in the table, in alphabetical order of code.
+        pub const ALL: &[CurrencyInfo] = &[$($name::INFO),*];
+    };
+}
+
+currencies! {
+    Aed => ("AED", 784, "AED", 2, "UAE dirham"),
+    Ars => ("ARS", 32, "AR$", 2, "Argentine peso"),
+    Aud => ("AUD", 36, "A$", 2, "Australian dollar"),
+    Bhd => ("BHD", 48, "BD", 3, "Bahraini dinar"),
+    Brl => ("BRL", 986, "R$", 2, "Brazilian real"),
+    Cad => ("CAD", 124, "CA$", 2, "Canadian dollar"),
+    Chf => ("CHF", 756, "CHF", 2, "Swiss franc"),
+    Clf => ("CLF", 990, "UF", 4, "Unidad de Fomento"),
+    Clp => ("CLP", 152, "CLP$", 0, "Chilean peso"),
+    Cny => ("CNY", 156, "CN¥", 2, "Renminbi"),
+    Cop => ("COP", 170, "COL$", 2, "Colombian peso"),
+    Czk => ("CZK", 203, "Kč", 2, "Czech koruna"),
+    Dkk => ("DKK", 208, "kr.", 2, "Danish krone"),
+    Egp => ("EGP", 818, "E£", 2, "Egyptian pound"),
+    Eur => ("EUR", 978, "€", 2, "euro"),
+    Gbp => ("GBP", 826, "£", 2, "pound sterling"),
+    Hkd => ("HKD", 344, "HK$", 2, "Hong Kong dollar"),
+    Huf => ("HUF", 348, "Ft", 2, "Hungarian forint"),
+    Idr => ("IDR", 360, "Rp", 2, "Indonesian rupiah"),
+    Ils => ("ILS", 376, "₪", 2, "Israeli new shekel"),
+    Inr => ("INR", 356, "₹", 2, "Indian rupee"),
+    Iqd => ("IQD", 368, "IQD", 3, "Iraqi dinar"),
+    Isk => ("ISK", 352, "ISK", 0, "Icelandic króna"),
+    Jod => ("JOD", 400, "JD", 3, "Jordanian dinar"),
+    Jpy => ("JPY", 392, "¥", 0, "Japanese yen"),
+    Kes => ("KES", 404, "KSh", 2, "Kenyan shilling"),
+    Krw => ("KRW", 410, "₩", 0, "South Korean won"),
+    Kwd => ("KWD", 414, "KD", 3, "Kuwaiti dinar"),
+    Lyd => ("LYD", 434, "LD", 3, "Libyan dinar"),
+    Mxn => ("MXN", 484, "MX$", 2, "Mexican peso"),
+    Myr => ("MYR", 458, "RM", 2, "Malaysian ringgit"),
+    Ngn => ("NGN", 566, "₦", 2, "Nigerian naira"),
+    Nok => ("NOK", 578, "NOK", 2, "Norwegian krone"),
+    Nzd => ("NZD", 554, "NZ$", 2, "New Zealand dollar"),
+    Omr => ("OMR", 512, "OMR", 3, "Omani rial"),
+    Pen => ("PEN", 604, "S/", 2, "Peruvian sol"),
+    Php => ("PHP", 608, "₱", 2, "Philippine peso"),
+    Pkr => ("PKR", 586, "Rs", 2, "Pakistani rupee"),
+    Pln => ("PLN", 985, "zł", 2, "Polish złoty"),
+    Pyg => ("PYG", 600, "₲", 0, "Paraguayan guaraní"),
+    Rub => ("RUB", 643, "₽", 2, "Russian ruble"),
+    Sar => ("SAR", 682, "SAR", 2, "Saudi riyal"),
+    Sek => ("SEK", 752, "SEK", 2, "Swedish krona"),
+    Sgd => ("SGD", 702, "S$", 2, "Singapore dollar"),
+    Thb => ("THB", 764, "฿", 2, "Thai baht"),
+    Tnd => ("TND", 788, "DT", 3, "Tunisian dinar"),
+    Try => ("TRY", 949, "₺", 2, "Turkish lira"),
+    Twd => ("TWD", 901, "NT$", 2, "New Taiwan dollar"),
+    Ugx => ("UGX", 800, "USh", 0, "Ugandan shilling"),
+    Usd => ("USD", 840, "$", 2, "US dollar"),
+    Vnd => ("VND", 704, "₫", 0, "Vietnamese đồng"),
+    Xaf => ("XAF", 950, "FCFA", 0, "Central African CFA franc"),
+    Xof => ("XOF", 952, "CFA", 0, "West African CFA franc"),
+    Zar => ("ZAR", 710, "R", 2, "South African rand"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookups() {
+        assert_eq!(CurrencyInfo::from_code("KWD"), Some(Kwd::INFO));
+        assert_eq!(CurrencyInfo::from_code("jpy"), Some(Jpy::INFO));
+        assert_eq!(CurrencyInfo::from_code("XXX"), None);
+        assert_eq!(CurrencyInfo::from_numeric(978), Some(Eur::INFO));
+        assert_eq!(CurrencyInfo::from_numeric(0), None);
+    }
+
+    #[test]
+    fn table_is_consistent() {
+        for pair in ALL.windows(2) {
+            assert!(
+                pair[0].code() < pair[1].code(),
+                "{} is out of order",
+                pair[1]
+            );
+        }
+
+        for info in ALL {
+            assert_eq!(info.code().len(), 3);
+            assert!(info.code().bytes().all(|b| b.is_ascii_uppercase()));
+            assert!(info.exponent() <= 4);
+            assert_eq!(CurrencyInfo::from_numeric(info.numeric()), Some(*info));
+        }
+    }
+}
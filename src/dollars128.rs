@@ -0,0 +1,227 @@
+use std::iter::Sum;
+use std::str::FromStr;
+
+use crate::format::{Amount, Formatted};
+use crate::macros::impl_amount;
+use crate::{parse, Dollars, FormatOptions, ParseError};
+
+/// A dollar value backed by an `i128` number of cents, for aggregates too large for [`Dollars`].
+///
+/// This has the same formatting, parsing, and arithmetic as [`Dollars`], just with a range of
+/// about ±$1.7 × 10<sup>36</sup> instead of ±$92 quadrillion. Every [`Dollars`] converts into a
+/// `Dollars128` losslessly, and sums of [`Dollars`] can be collected directly into one:
+///
+/// ```
+/// # use dollars::{Dollars, Dollars128};
+/// let total: Dollars128 = [Dollars::MAX, Dollars::MAX].into_iter().sum();
+///
+/// assert_eq!(total.in_cents(), 2 * i64::MAX as i128);
+/// assert!(Dollars::try_from(total).is_err());
+/// ```
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Dollars128 {
+    cent_value: i128,
+}
+
+impl Dollars128 {
+    /// The largest representable value.
+    pub const MAX: Self = Self::from_cents(i128::MAX);
+    /// The smallest representable value.
+    pub const MIN: Self = Self::from_cents(i128::MIN);
+    /// Zero dollars.
+    pub const ZERO: Self = Self::from_cents(0);
+
+    /// Constructs a value from a number of cents.
+    pub const fn from_cents(cent_value: i128) -> Self {
+        Self { cent_value }
+    }
+
+    /// The dollars portion of the value.
+    pub const fn dollars(&self) -> i128 {
+        (self.cent_value / 100).abs()
+    }
+
+    /// The cents portion of the value.
+    pub const fn cents(&self) -> i128 {
+        (self.cent_value % 100).abs()
+    }
+
+    /// The value in cents.
+    pub const fn in_cents(&self) -> i128 {
+        self.cent_value
+    }
+
+    /// Formats the value according to `options`.
+    pub fn format_with(self, options: &FormatOptions) -> Formatted<'_> {
+        Formatted::new(Amount::new(self.in_cents(), 2), options)
+    }
+}
+
+impl_amount! {
+    impl[] Dollars128 {
+        repr: (i128, u128),
+        from: from_cents,
+        get: in_cents,
+        /// Formats the value like `-$1234.56`, respecting the same flags as [`Dollars`].
+        display: FormatOptions::DEFAULT,
+    }
+}
+
+impl From<Dollars> for Dollars128 {
+    fn from(value: Dollars) -> Self {
+        Self::from_cents(value.in_cents().into())
+    }
+}
+
+impl From<i128> for Dollars128 {
+    fn from(cent_value: i128) -> Self {
+        Self::from_cents(cent_value)
+    }
+}
+
+/// Parses a value with the same grammar as [`Dollars`], over the wider range.
+impl FromStr for Dollars128 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        parse::parse_bounded(s, "$", 2, i128::MIN, i128::MAX).map(Self::from)
+    }
+}
+
+/// Sums values of [`Dollars`] without overflowing, unless there are more than 2<sup>64</sup> of
+/// them.
+impl Sum<Dollars> for Dollars128 {
+    fn sum<I: Iterator<Item = Dollars>>(iter: I) -> Self {
+        iter.map(Self::from).sum()
+    }
+}
+
+impl<'a> Sum<&'a Dollars> for Dollars128 {
+    fn sum<I: Iterator<Item = &'a Dollars>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl TryFrom<Dollars128> for Dollars {
+    type Error = TryFromDollars128Error;
+
+    fn try_from(value: Dollars128) -> Result<Self, TryFromDollars128Error> {
+        i64::try_from(value.in_cents())
+            .map(Self::from)
+            .map_err(|_| TryFromDollars128Error(()))
+    }
+}
+
+/// Error returned when a [`Dollars128`] is out of range for [`Dollars`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("value out of range for Dollars")]
+pub struct TryFromDollars128Error(());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseErrorKind, RoundingMode};
+
+    #[test]
+    fn conversions() {
+        for cents in [0, 1234, -5, i64::MAX, i64::MIN] {
+            let value = Dollars::from(cents);
+
+            assert_eq!(Dollars::try_from(Dollars128::from(value)), Ok(value));
+        }
+
+        assert_eq!(
+            Dollars::try_from(Dollars128::from(Dollars::MAX) + Dollars128::from(1)),
+            Err(TryFromDollars128Error(()))
+        );
+        assert!(Dollars::try_from(Dollars128::MIN).is_err());
+    }
+
+    #[test]
+    fn printing_and_parsing() {
+        assert_eq!(Dollars128::from(-123456).to_string(), "-$1234.56");
+        assert_eq!(
+            format!("{:#}", Dollars128::MAX),
+            "$1,701,411,834,604,692,317,316,873,037,158,841,057.27"
+        );
+        assert_eq!(
+            Dollars128::MIN.to_string(),
+            "-$1701411834604692317316873037158841057.28"
+        );
+
+        for value in [
+            Dollars128::ZERO,
+            Dollars128::from(-5),
+            Dollars128::MAX,
+            Dollars128::MIN,
+        ] {
+            assert_eq!(value.to_string().parse::<Dollars128>(), Ok(value));
+        }
+
+        assert_eq!(
+            "$92233720368547758.08".parse::<Dollars128>(),
+            Ok(Dollars128::from(i64::MAX as i128 + 1))
+        );
+        assert_eq!(
+            "$1701411834604692317316873037158841057.28"
+                .parse::<Dollars128>()
+                .unwrap_err()
+                .kind(),
+            ParseErrorKind::Overflow
+        );
+        assert_eq!(
+            "$1.5".parse::<Dollars128>().unwrap_err().kind(),
+            ParseErrorKind::BadCentsLength
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let value = Dollars128::from(1000);
+
+        assert_eq!(value + value - Dollars128::from(1), Dollars128::from(1999));
+        assert_eq!(value * 3 / 4 % 7, Dollars128::from(1));
+        assert_eq!(
+            value.div_round(3, RoundingMode::Ceil),
+            Dollars128::from(334)
+        );
+        assert_eq!(
+            Dollars128::MIN.checked_div_round(-1, RoundingMode::Floor),
+            None
+        );
+        assert_eq!(Dollars128::MAX.checked_add(Dollars128::from(1)), None);
+        assert_eq!(
+            Dollars128::checked_sum([Dollars128::MAX, Dollars128::MIN]),
+            Some(Dollars128::from(-1))
+        );
+        assert_eq!(4 * value, value * 4);
+    }
+
+    #[test]
+    fn overflow_variants_and_sign() {
+        let one = Dollars128::from(1);
+
+        assert_eq!(Dollars128::MAX.saturating_add(one), Dollars128::MAX);
+        assert_eq!(Dollars128::MIN.saturating_neg(), Dollars128::MAX);
+        assert_eq!(Dollars128::MAX.wrapping_add(one), Dollars128::MIN);
+        assert_eq!(
+            Dollars128::MIN.overflowing_sub(one),
+            (Dollars128::MAX, true)
+        );
+        assert_eq!(Dollars128::from(-5).signum(), -1);
+        assert_eq!(Dollars128::from(-5).abs(), Dollars128::from(5));
+        assert_eq!(Dollars128::MIN.checked_abs(), None);
+        assert_eq!(Dollars128::MIN.unsigned_abs(), 1 << 127);
+    }
+
+    #[test]
+    fn summing_dollars() {
+        let values = [Dollars::MAX; 4];
+
+        assert_eq!(
+            values.iter().sum::<Dollars128>(),
+            Dollars128::from(4 * i64::MAX as i128)
+        );
+        assert_eq!(Dollars::checked_sum(values), None);
+    }
+}
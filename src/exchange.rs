@@ -0,0 +1,426 @@
+//! Currency conversion with exact exchange rates.
+//!
+//! Rates come from an [`ExchangeRateProvider`], like [`InMemoryRates`] or [`CsvRates`], and are
+//! applied with [`convert`]:
+//!
+//! ```
+//! use dollars::currency::{Eur, Usd};
+//! use dollars::exchange::{self, ExchangeRate, InMemoryRates};
+//! use dollars::{Money, Rate, RoundingMode};
+//!
+//! let mut rates = InMemoryRates::new();
+//! rates.insert::<Usd, Eur>(ExchangeRate::new(Rate::new(923456, 6)));
+//!
+//! let price = Money::<Usd>::from_minor_units(1999);
+//! let converted = exchange::convert(price, Eur, &rates, RoundingMode::HalfEven).unwrap();
+//!
+//! assert_eq!(converted.to_string(), "€18.46");
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use crate::currency::{Currency, CurrencyInfo};
+use crate::{rounding, Money, ParseRateError, Rate, RoundingMode};
+
+/// An exact, positive exchange rate: the amount of the target currency that one unit of the
+/// source currency buys, like `0.923456` for USD to EUR.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ExchangeRate {
+    rate: Rate,
+}
+
+impl ExchangeRate {
+    /// Constructs an exchange rate from a decimal rate.
+    ///
+    /// Panics if `rate` isn't positive; see [`checked_new`](ExchangeRate::checked_new) for a
+    /// non-panicking version.
+    pub fn new(rate: Rate) -> Self {
+        Self::checked_new(rate).expect("exchange rate must be positive")
+    }
+
+    /// Constructs an exchange rate from a decimal rate. Returns `None` if `rate` isn't positive.
+    pub fn checked_new(rate: Rate) -> Option<Self> {
+        (rate.mantissa() > 0).then_some(Self { rate })
+    }
+
+    /// The underlying decimal rate.
+    pub fn rate(&self) -> Rate {
+        self.rate
+    }
+}
+
+impl Display for ExchangeRate {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.rate, f)
+    }
+}
+
+/// A source of exchange rates.
+pub trait ExchangeRateProvider {
+    /// The rate for converting from the currency `from` to the currency `to`, if known.
+    fn rate(&self, from: CurrencyInfo, to: CurrencyInfo) -> Option<ExchangeRate>;
+}
+
+/// Exchange rates held in memory, for tests or rates fetched ahead of time.
+///
+/// Rates are directional: a rate from USD to EUR isn't used for converting EUR to USD, since its
+/// inverse generally isn't an exact decimal.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryRates {
+    rates: HashMap<(&'static str, &'static str), ExchangeRate>,
+}
+
+impl InMemoryRates {
+    /// Constructs an empty set of rates.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the rate for converting from `F` to `T`, replacing any previous one.
+    pub fn insert<F: Currency, T: Currency>(&mut self, rate: ExchangeRate) {
+        self.insert_info(F::INFO, T::INFO, rate);
+    }
+
+    /// Sets the rate for converting from `from` to `to`, replacing any previous one.
+    pub fn insert_info(&mut self, from: CurrencyInfo, to: CurrencyInfo, rate: ExchangeRate) {
+        self.rates.insert((from.code(), to.code()), rate);
+    }
+
+    /// The number of rates.
+    pub fn len(&self) -> usize {
+        self.rates.len()
+    }
+
+    /// Whether or not there are no rates.
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+
+    /// Parses rates from CSV text, as described for [`CsvRates`].
+    pub fn from_csv(text: &str) -> Result<Self, LoadRatesError> {
+        let mut rates = Self::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') || (index == 0 && line == "from,to,rate") {
+                continue;
+            }
+
+            let fields: Vec<_> = line.split(',').map(str::trim).collect();
+            let [from, to, rate] = fields[..] else {
+                return Err(LoadRatesError::FieldCount {
+                    line: line_number,
+                    count: fields.len(),
+                });
+            };
+            let currency = |code: &str| {
+                CurrencyInfo::from_code(code).ok_or_else(|| LoadRatesError::UnknownCurrency {
+                    line: line_number,
+                    code: code.to_string(),
+                })
+            };
+            let (from, to) = (currency(from)?, currency(to)?);
+            let rate = rate.parse().map_err(|source| LoadRatesError::InvalidRate {
+                line: line_number,
+                source,
+            })?;
+            let rate = ExchangeRate::checked_new(rate)
+                .ok_or(LoadRatesError::NonPositiveRate { line: line_number })?;
+
+            rates.insert_info(from, to, rate);
+        }
+
+        Ok(rates)
+    }
+}
+
+impl ExchangeRateProvider for InMemoryRates {
+    fn rate(&self, from: CurrencyInfo, to: CurrencyInfo) -> Option<ExchangeRate> {
+        self.rates.get(&(from.code(), to.code())).copied()
+    }
+}
+
+/// Exchange rates loaded from a CSV file, for offline use.
+///
+/// Each line of the file has the form `from,to,rate`, like `USD,EUR,0.923456`, where the
+/// currencies are ISO 4217 codes and the rate is an exact decimal. An optional `from,to,rate`
+/// header, blank lines, and lines starting with `#` are ignored.
+#[derive(Clone, Debug)]
+pub struct CsvRates {
+    path: PathBuf,
+    rates: InMemoryRates,
+}
+
+impl CsvRates {
+    /// Loads the rates from the file at `path`.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, LoadRatesError> {
+        let path = path.as_ref().to_path_buf();
+        let rates = InMemoryRates::from_csv(&fs::read_to_string(&path)?)?;
+
+        Ok(Self { path, rates })
+    }
+
+    /// Reloads the rates from the file, picking up any changes since it was last read.
+    ///
+    /// If the file can't be loaded, the previous rates are left untouched.
+    pub fn reload(&mut self) -> Result<(), LoadRatesError> {
+        self.rates = InMemoryRates::from_csv(&fs::read_to_string(&self.path)?)?;
+        Ok(())
+    }
+
+    /// The path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl ExchangeRateProvider for CsvRates {
+    fn rate(&self, from: CurrencyInfo, to: CurrencyInfo) -> Option<ExchangeRate> {
+        self.rates.rate(from, to)
+    }
+}
+
+/// Converts `amount` into the currency `target` using a rate from `provider`, rounding the
+/// result to the target's minor unit according to `mode`.
+///
+/// The conversion is done with exact `i128` intermediates, so the result is only ever rounded
+/// once, even for large amounts at very precise rates. Converting to the same currency always
+/// leaves the amount unchanged, without consulting the provider.
+///
+/// `target` is only used to pick the currency, like `Eur` in `convert(price, Eur, ...)`.
+pub fn convert<F, T, P>(
+    amount: Money<F>,
+    target: T,
+    provider: &P,
+    mode: RoundingMode,
+) -> Result<Money<T>, ExchangeError>
+where
+    F: Currency,
+    T: Currency,
+    P: ExchangeRateProvider + ?Sized,
+{
+    let _ = target;
+    if F::INFO == T::INFO {
+        return Ok(Money::from_minor_units(amount.minor_units()));
+    }
+
+    let rate = provider
+        .rate(F::INFO, T::INFO)
+        .ok_or(ExchangeError::MissingRate {
+            from: F::INFO,
+            to: T::INFO,
+        })?
+        .rate();
+
+    // amount * rate, rescaled from the source's minor unit to the target's. The product of two
+    // i64s always fits in an i128, and the powers of ten cancel out so that the product is only
+    // ever multiplied or divided, never both; multiplying can then only overflow if the result
+    // would overflow anyway
+    let product = amount.minor_units() as i128 * rate.mantissa() as i128;
+    let (up, down) = (T::INFO.exponent(), F::INFO.exponent() + rate.scale());
+    let numerator = product
+        .checked_mul(10_i128.pow(up.saturating_sub(down)))
+        .ok_or(ExchangeError::Overflow)?;
+    let denominator = 10_i128.pow(down.saturating_sub(up));
+
+    i64::try_from(rounding::div_round(numerator, denominator, mode))
+        .map(Money::from_minor_units)
+        .map_err(|_| ExchangeError::Overflow)
+}
+
+/// Error capturing a failure to convert between currencies.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ExchangeError {
+    /// The provider has no rate between the two currencies.
+    #[error("no exchange rate from {from} to {to}")]
+    MissingRate {
+        from: CurrencyInfo,
+        to: CurrencyInfo,
+    },
+
+    /// The converted amount doesn't fit in the target's representation.
+    #[error("converted amount overflows")]
+    Overflow,
+}
+
+/// Error capturing a failure to load exchange rates from CSV.
+#[derive(Debug, thiserror::Error)]
+pub enum LoadRatesError {
+    /// The file couldn't be read.
+    #[error("failed to read exchange rates: {0}")]
+    Io(#[from] io::Error),
+
+    /// A line doesn't have exactly three fields.
+    #[error("expected 3 fields on line {line}, found {count}")]
+    FieldCount { line: usize, count: usize },
+
+    /// A currency code isn't in the [currency table](crate::currency::ALL).
+    #[error("unknown currency '{code}' on line {line}")]
+    UnknownCurrency { line: usize, code: String },
+
+    /// A rate isn't a valid decimal.
+    #[error("invalid exchange rate on line {line}")]
+    InvalidRate {
+        line: usize,
+        #[source]
+        source: ParseRateError,
+    },
+
+    /// A rate is zero or negative.
+    #[error("exchange rate on line {line} isn't positive")]
+    NonPositiveRate { line: usize },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::currency::{Eur, Jpy, Kwd, Usd};
+    use crate::Dollars;
+
+    fn rates() -> InMemoryRates {
+        InMemoryRates::from_csv(
+            "from,to,rate\n\
+             # rates as of close\n\
+             USD,EUR,0.923456\n\
+             USD,JPY,151.37\n\
+             usd,kwd,0.307\n\
+             \n\
+             JPY,USD,0.006606\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn conversions() {
+        let rates = rates();
+        let price = Dollars::from(1999);
+
+        assert_eq!(
+            convert(price, Eur, &rates, RoundingMode::HalfEven),
+            Ok(Money::from_minor_units(1846))
+        );
+        assert_eq!(
+            convert(price, Eur, &rates, RoundingMode::Floor),
+            Ok(Money::from_minor_units(1845))
+        );
+        assert_eq!(
+            convert(price, Jpy, &rates, RoundingMode::HalfEven),
+            Ok(Money::from_minor_units(3026))
+        );
+        assert_eq!(
+            convert(price, Kwd, &rates, RoundingMode::HalfEven),
+            Ok(Money::from_minor_units(6137))
+        );
+        assert_eq!(
+            convert(
+                Money::<Jpy>::from_minor_units(-3026),
+                Usd,
+                &rates,
+                RoundingMode::HalfUp
+            ),
+            Ok(Money::from_minor_units(-1999))
+        );
+        assert_eq!(
+            convert(price, Usd, &InMemoryRates::new(), RoundingMode::Truncate),
+            Ok(price)
+        );
+    }
+
+    #[test]
+    fn large_amounts_stay_exact() {
+        let rates = rates();
+        let billion = Money::<Usd>::from_minor_units(100_000_000_000);
+
+        assert_eq!(
+            convert(billion, Eur, &rates, RoundingMode::Truncate),
+            Ok(Money::from_minor_units(92_345_600_000))
+        );
+        assert_eq!(
+            convert(Money::<Usd>::MAX, Eur, &rates, RoundingMode::Truncate),
+            Ok(Money::from_minor_units(8_517_378_247_665_763_847))
+        );
+        assert_eq!(
+            convert(Money::<Usd>::MAX, Jpy, &rates, RoundingMode::Truncate),
+            Err(ExchangeError::Overflow)
+        );
+
+        let mut precise = InMemoryRates::new();
+        precise.insert::<Usd, Eur>(ExchangeRate::new("0.923456789012345678".parse().unwrap()));
+
+        assert_eq!(
+            convert(
+                Money::<Usd>::from_minor_units(5_000_000_000_000_000_000),
+                Eur,
+                &precise,
+                RoundingMode::HalfEven
+            ),
+            Ok(Money::from_minor_units(4_617_283_945_061_728_390))
+        );
+    }
+
+    #[test]
+    fn missing_rates() {
+        let rates = rates();
+
+        assert_eq!(
+            convert(Money::<Eur>::ZERO, Usd, &rates, RoundingMode::HalfEven),
+            Err(ExchangeError::MissingRate {
+                from: Eur::INFO,
+                to: Usd::INFO,
+            })
+        );
+        assert_eq!(
+            convert(Money::<Eur>::ZERO, Usd, &rates, RoundingMode::HalfEven)
+                .unwrap_err()
+                .to_string(),
+            "no exchange rate from EUR to USD"
+        );
+    }
+
+    #[test]
+    fn csv_errors() {
+        let line_of = |text: &str| match InMemoryRates::from_csv(text).unwrap_err() {
+            LoadRatesError::FieldCount { line, .. }
+            | LoadRatesError::UnknownCurrency { line, .. }
+            | LoadRatesError::InvalidRate { line, .. }
+            | LoadRatesError::NonPositiveRate { line } => line,
+            LoadRatesError::Io(err) => panic!("unexpected error: {}", err),
+        };
+
+        assert_eq!(line_of("USD,EUR"), 1);
+        assert_eq!(line_of("USD,EUR,0.9\nUSD,XXX,1"), 2);
+        assert_eq!(line_of("\nUSD,EUR,0.9.1"), 2);
+        assert_eq!(line_of("USD,EUR,-0.9"), 1);
+        assert_eq!(line_of("USD,EUR,0"), 1);
+        assert_eq!(line_of("USD,EUR,0.9,1"), 1);
+    }
+
+    #[test]
+    fn csv_file() {
+        let path = std::env::temp_dir().join(format!("dollars-rates-{}.csv", std::process::id()));
+        fs::write(&path, "USD,EUR,0.9\n").unwrap();
+
+        let mut rates = CsvRates::open(&path).unwrap();
+        assert_eq!(
+            rates.rate(Usd::INFO, Eur::INFO),
+            Some(ExchangeRate::new(Rate::new(9, 1)))
+        );
+
+        fs::write(&path, "USD,EUR,0.95\nEUR,USD,1.05\n").unwrap();
+        rates.reload().unwrap();
+        assert_eq!(
+            rates.rate(Usd::INFO, Eur::INFO),
+            Some(ExchangeRate::new(Rate::new(95, 2)))
+        );
+        assert!(rates.rate(Eur::INFO, Usd::INFO).is_some());
+
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(rates.reload(), Err(LoadRatesError::Io(_))));
+        assert!(rates.rate(Eur::INFO, Usd::INFO).is_some());
+    }
+}
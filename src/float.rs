@@ -0,0 +1,197 @@
+use crate::{rounding, Dollars, RoundingMode};
+
+/// The magnitude in dollars, 2<sup>46</sup>, from which floats are more than a cent apart.
+const EXACT_LIMIT: f64 = (1_u64 << 46) as f64;
+
+impl Dollars {
+    /// The value as a floating-point number of dollars, like `-1234.56`.
+    ///
+    /// The result is the closest `f64` to the exact value for anything under about $90
+    /// trillion (2<sup>53</sup> cents); larger values may be off by one unit in the last place.
+    pub fn to_f64(self) -> f64 {
+        self.in_cents() as f64 / 100.0
+    }
+
+    /// Converts a floating-point number of dollars, rounding its exact value to a whole number
+    /// of cents according to `mode`.
+    ///
+    /// Most decimal amounts can't be represented exactly as floats (`0.1` is really
+    /// `0.1000000000000000055...`), so the rounding mode matters even for values that look like
+    /// whole cents: `0.1` converts to 10 cents with [`RoundingMode::HalfEven`], but 11 with
+    /// [`RoundingMode::Ceil`].
+    ///
+    /// Returns an error if `value` is NaN, infinite, or out of range.
+    pub fn try_from_f64(value: f64, mode: RoundingMode) -> Result<Self, ConversionError> {
+        if value.is_nan() {
+            return Err(ConversionError::NaN);
+        }
+
+        if value.is_infinite() {
+            return Err(ConversionError::Infinite);
+        }
+
+        // decompose the value exactly into `mantissa * 2^exponent`
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as i128;
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+        let numerator = if value < 0.0 { -mantissa } else { mantissa } * 100;
+
+        let cent_value = if exponent >= 0 {
+            // at least 2^66 cents is out of range anyway, and this keeps the shift from overflowing
+            if exponent > 66 {
+                return Err(ConversionError::OutOfRange);
+            }
+
+            numerator << exponent
+        } else if exponent >= -120 {
+            rounding::div_round(numerator, 1 << -exponent, mode)
+        } else {
+            // the magnitude is less than 2^-60 cents, so only its sign matters for rounding
+            rounding::div_round(numerator.signum(), 4, mode)
+        };
+
+        i64::try_from(cent_value)
+            .map(Self::from)
+            .map_err(|_| ConversionError::OutOfRange)
+    }
+
+    /// Converts a floating-point number of dollars that represents a whole number of cents.
+    ///
+    /// A value counts as a whole number of cents if it's the closest `f64` to exactly one, so
+    /// `0.1` and `19.99` are accepted, but `0.125` and `0.1 + 0.2` aren't. In other words, this
+    /// accepts exactly the values that [`to_f64`](Dollars::to_f64) produces under 2<sup>46</sup>
+    /// dollars (about $70 trillion). From there up, floats are more than a cent apart, so a
+    /// float can stand for more than one cent, and those values are rejected as inexact.
+    ///
+    /// Returns an error if `value` is NaN, infinite, out of range, or not a whole number of
+    /// cents.
+    pub fn try_from_f64_exact(value: f64) -> Result<Self, ConversionError> {
+        let dollars = Self::try_from_f64(value, RoundingMode::HalfEven)?;
+
+        if dollars.is_zero() && value == 0.0 {
+            return Ok(dollars);
+        }
+
+        // a float that a neighboring cent also converts to is ambiguous, which is always possible
+        // from 2^46 dollars up
+        let is_ambiguous = value.abs() >= EXACT_LIMIT
+            || [-1, 1].into_iter().any(|offset| {
+                dollars
+                    .checked_add(Self::from(offset))
+                    .is_some_and(|neighbor| neighbor.to_f64() == value)
+            });
+
+        if dollars.to_f64() == value && !is_ambiguous {
+            Ok(dollars)
+        } else {
+            Err(ConversionError::Inexact)
+        }
+    }
+}
+
+/// Error capturing a failure to convert a floating-point number to [`Dollars`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ConversionError {
+    /// The value is NaN.
+    #[error("value is NaN")]
+    NaN,
+
+    /// The value is positive or negative infinity.
+    #[error("value is infinite")]
+    Infinite,
+
+    /// The value is too large in magnitude to fit in [`Dollars`].
+    #[error("value out of range for Dollars")]
+    OutOfRange,
+
+    /// The value isn't a whole number of cents.
+    #[error("value isn't a whole number of cents")]
+    Inexact,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_f64() {
+        assert_eq!(Dollars::from(-123456).to_f64(), -1234.56);
+        assert_eq!(Dollars::from(10).to_f64(), 0.1);
+        assert_eq!(Dollars::ZERO.to_f64(), 0.0);
+        assert_eq!(Dollars::MAX.to_f64(), 92233720368547758.07);
+    }
+
+    #[test]
+    fn rounding_conversions() {
+        let convert = |value: f64, mode| Dollars::try_from_f64(value, mode).map(|d| d.in_cents());
+
+        assert_eq!(convert(0.1, RoundingMode::HalfEven), Ok(10));
+        assert_eq!(convert(0.1, RoundingMode::Ceil), Ok(11));
+        assert_eq!(convert(0.1, RoundingMode::Floor), Ok(10));
+        assert_eq!(convert(-0.1, RoundingMode::Floor), Ok(-11));
+        assert_eq!(convert(0.125, RoundingMode::HalfEven), Ok(12));
+        assert_eq!(convert(0.125, RoundingMode::HalfUp), Ok(13));
+        assert_eq!(convert(-0.125, RoundingMode::HalfAwayFromZero), Ok(-13));
+        assert_eq!(convert(1e10, RoundingMode::Truncate), Ok(1_000_000_000_000));
+        assert_eq!(convert(-0.0, RoundingMode::HalfEven), Ok(0));
+        assert_eq!(convert(f64::MIN_POSITIVE, RoundingMode::Ceil), Ok(1));
+        assert_eq!(convert(-5e-324, RoundingMode::Floor), Ok(-1));
+        assert_eq!(convert(5e-324, RoundingMode::HalfUp), Ok(0));
+        assert_eq!(
+            convert(-90071992547409.92, RoundingMode::Truncate),
+            Ok(-(1 << 53))
+        );
+    }
+
+    #[test]
+    fn conversion_errors() {
+        let convert = |value: f64| Dollars::try_from_f64(value, RoundingMode::HalfEven);
+
+        assert_eq!(convert(f64::NAN), Err(ConversionError::NaN));
+        assert_eq!(convert(f64::INFINITY), Err(ConversionError::Infinite));
+        assert_eq!(convert(f64::NEG_INFINITY), Err(ConversionError::Infinite));
+        assert_eq!(convert(1e17), Err(ConversionError::OutOfRange));
+        assert_eq!(convert(f64::MAX), Err(ConversionError::OutOfRange));
+        assert_eq!(
+            convert(Dollars::MIN.to_f64()),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn exact_conversions() {
+        assert_eq!(Dollars::try_from_f64_exact(19.99), Ok(Dollars::from(1999)));
+        assert_eq!(Dollars::try_from_f64_exact(-0.0), Ok(Dollars::ZERO));
+        assert_eq!(
+            Dollars::try_from_f64_exact(0.125),
+            Err(ConversionError::Inexact)
+        );
+        assert_eq!(
+            Dollars::try_from_f64_exact(0.1 + 0.2),
+            Err(ConversionError::Inexact)
+        );
+        assert_eq!(
+            Dollars::try_from_f64_exact(f64::NAN),
+            Err(ConversionError::NaN)
+        );
+
+        let limit = (1 << 46) * 100;
+
+        for cents in [0, 1, -1, 1999, -123456, limit - 1, -(limit - 1)] {
+            let value = Dollars::from(cents);
+            assert_eq!(Dollars::try_from_f64_exact(value.to_f64()), Ok(value));
+        }
+
+        for cents in [limit, -limit, 7036880940971854, 1 << 53] {
+            assert_eq!(
+                Dollars::try_from_f64_exact(Dollars::from(cents).to_f64()),
+                Err(ConversionError::Inexact)
+            );
+        }
+    }
+}
@@ -0,0 +1,435 @@
+use std::fmt::{self, Alignment, Display, Formatter, Write};
+
+use crate::ParseOptions;
+
+/// Options controlling how an amount of [`Money`](crate::Money) is formatted by
+/// [`format_with`](crate::Money::format_with).
+///
+/// The options are built up from [`FormatOptions::new`], which matches the
+/// [`Display`] impl for [`Dollars`](crate::Dollars):
+///
+/// ```
+/// # use dollars::{Dollars, FormatOptions, NegativeStyle};
+/// let options = FormatOptions::new()
+///     .grouping_separator(Some(','))
+///     .negative_style(NegativeStyle::Parentheses);
+///
+/// assert_eq!(Dollars::from(-123456).format_with(&options).to_string(), "($1,234.56)");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FormatOptions {
+    symbol: &'static str,
+    symbol_position: SymbolPosition,
+    symbol_spacing: bool,
+    symbol_separator: char,
+    decimal_mark: char,
+    grouping_separator: Option<char>,
+    group_size: u8,
+    negative_style: NegativeStyle,
+    sign_policy: SignPolicy,
+}
+
+impl FormatOptions {
+    /// Accounting style, like `($1,234.56)` for negative values.
+    pub const ACCOUNTING: Self = Self::GROUPED.negative_style(NegativeStyle::Parentheses);
+    /// The default options, like `-$1234.56`. This matches the [`Display`] impl.
+    pub const DEFAULT: Self = Self::new();
+    /// Grouped thousands, like `-$1,234.56`.
+    pub const GROUPED: Self = Self::new().grouping_separator(Some(','));
+
+    /// The default options, like `-$1234.56`. This matches the [`Display`] impl.
+    pub const fn new() -> Self {
+        Self {
+            symbol: "$",
+            symbol_position: SymbolPosition::Prefix,
+            symbol_spacing: false,
+            symbol_separator: ' ',
+            decimal_mark: '.',
+            grouping_separator: None,
+            group_size: 3,
+            negative_style: NegativeStyle::Minus,
+            sign_policy: SignPolicy::NegativeOnly,
+        }
+    }
+
+    /// Sets the currency symbol. Defaults to `$`.
+    pub const fn symbol(mut self, symbol: &'static str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Sets where the currency symbol goes. Defaults to [`SymbolPosition::Prefix`].
+    pub const fn symbol_position(mut self, symbol_position: SymbolPosition) -> Self {
+        self.symbol_position = symbol_position;
+        self
+    }
+
+    /// Sets whether or not there's a space between the currency symbol and the number, like
+    /// `1234.56 USD`. Defaults to `false`.
+    pub const fn symbol_spacing(mut self, symbol_spacing: bool) -> Self {
+        self.symbol_spacing = symbol_spacing;
+        self
+    }
+
+    /// Sets the character used for [`symbol_spacing`](FormatOptions::symbol_spacing), like a
+    /// non-breaking space. Defaults to a regular space.
+    pub const fn symbol_separator(mut self, symbol_separator: char) -> Self {
+        self.symbol_separator = symbol_separator;
+        self
+    }
+
+    /// Sets the character separating dollars from cents. Defaults to `.`.
+    pub const fn decimal_mark(mut self, decimal_mark: char) -> Self {
+        self.decimal_mark = decimal_mark;
+        self
+    }
+
+    /// Sets the character separating groups of digits in the dollars portion, or `None` to not
+    /// group digits at all. Defaults to `None`.
+    pub const fn grouping_separator(mut self, grouping_separator: Option<char>) -> Self {
+        self.grouping_separator = grouping_separator;
+        self
+    }
+
+    /// Sets the number of digits in each group of the dollars portion. Defaults to 3.
+    ///
+    /// Panics if `group_size` is zero.
+    pub const fn group_size(mut self, group_size: u8) -> Self {
+        assert!(group_size > 0, "group size must be nonzero");
+        self.group_size = group_size;
+        self
+    }
+
+    /// Sets how negative values are marked. Defaults to [`NegativeStyle::Minus`].
+    pub const fn negative_style(mut self, negative_style: NegativeStyle) -> Self {
+        self.negative_style = negative_style;
+        self
+    }
+
+    /// Sets when a sign is shown. Defaults to [`SignPolicy::NegativeOnly`].
+    pub const fn sign_policy(mut self, sign_policy: SignPolicy) -> Self {
+        self.sign_policy = sign_policy;
+        self
+    }
+
+    /// Options for parsing values formatted with these options, for
+    /// [`parse_locale`](crate::Dollars::parse_locale).
+    pub(crate) const fn parse_options(&self) -> ParseOptions {
+        ParseOptions::new()
+            .symbol(self.symbol)
+            .symbol_position(self.symbol_position)
+            .allow_symbol_spacing(self.symbol_spacing)
+            .decimal_mark(self.decimal_mark)
+            .grouping_separator(self.grouping_separator)
+    }
+
+    /// Writes `amount` to `w`, with `zero_pad` extra leading zeros in front of the whole units.
+    fn write<W: Write>(&self, w: &mut W, amount: &Amount, zero_pad: usize) -> fmt::Result {
+        let use_parens = amount.is_negative && self.negative_style == NegativeStyle::Parentheses;
+
+        if use_parens {
+            w.write_char('(')?;
+        } else if amount.is_negative {
+            w.write_char('-')?;
+        } else if self.sign_policy == SignPolicy::Always {
+            w.write_char('+')?;
+        }
+
+        if self.symbol_position == SymbolPosition::Prefix {
+            w.write_str(self.symbol)?;
+
+            if self.symbol_spacing {
+                w.write_char(self.symbol_separator)?;
+            }
+        }
+
+        for _ in 0..zero_pad {
+            w.write_char('0')?;
+        }
+
+        self.write_units(w, amount.units)?;
+
+        if amount.scale > 0 {
+            w.write_char(self.decimal_mark)?;
+            write!(
+                w,
+                "{:0width$}",
+                amount.fraction,
+                width = amount.scale as usize
+            )?;
+        }
+
+        if self.symbol_position == SymbolPosition::Suffix {
+            if self.symbol_spacing {
+                w.write_char(self.symbol_separator)?;
+            }
+
+            w.write_str(self.symbol)?;
+        }
+
+        if use_parens {
+            w.write_char(')')?;
+        }
+
+        Ok(())
+    }
+
+    fn write_units<W: Write>(&self, w: &mut W, units: u128) -> fmt::Result {
+        let separator = match self.grouping_separator {
+            Some(separator) => separator,
+            None => return write!(w, "{}", units),
+        };
+
+        // format the digits into a buffer up front, since groups are counted from the right
+        let mut digits = [0; 39];
+        let mut len = 0;
+        let mut rest = units;
+
+        loop {
+            digits[len] = b'0' + (rest % 10) as u8;
+            len += 1;
+            rest /= 10;
+
+            if rest == 0 {
+                break;
+            }
+        }
+
+        for i in (0..len).rev() {
+            w.write_char(digits[i] as char)?;
+
+            if i > 0 && i % self.group_size as usize == 0 {
+                w.write_char(separator)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Where the currency symbol goes when formatting.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SymbolPosition {
+    /// Before the number, like `$1234.56`.
+    Prefix,
+
+    /// After the number, like `1234.56$`.
+    Suffix,
+
+    /// Nowhere, like `1234.56`.
+    Omitted,
+}
+
+/// How negative values are marked when formatting.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NegativeStyle {
+    /// A leading minus sign, like `-$1234.56`.
+    Minus,
+
+    /// Surrounding parentheses, like `($1234.56)`.
+    Parentheses,
+}
+
+/// When a sign is shown when formatting.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SignPolicy {
+    /// Only negative values are marked.
+    NegativeOnly,
+
+    /// Negative values are marked, and all other values get a leading `+`.
+    Always,
+}
+
+/// An amount of money formatted with custom [`FormatOptions`].
+///
+/// This is returned by [`format_with`](crate::Money::format_with); use its [`Display`] impl to
+/// actually format the value. Width, fill, and alignment flags are respected, with values
+/// left-aligned by default. The `+` flag forces a sign like [`SignPolicy::Always`] does, and the
+/// `0` flag pads with zeros between the symbol and the digits, like `-$0001.23`.
+#[derive(Clone, Copy, Debug)]
+pub struct Formatted<'a> {
+    amount: Amount,
+    options: &'a FormatOptions,
+}
+
+impl<'a> Formatted<'a> {
+    pub(crate) fn new(amount: Amount, options: &'a FormatOptions) -> Self {
+        Self { amount, options }
+    }
+}
+
+impl Display for Formatted<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let amount = &self.amount;
+        let options = if f.sign_plus() {
+            self.options.sign_policy(SignPolicy::Always)
+        } else {
+            *self.options
+        };
+
+        let width = match f.width() {
+            Some(width) => width,
+            None => return options.write(f, amount, 0),
+        };
+
+        let mut counter = CharCounter(0);
+        options.write(&mut counter, amount, 0)?;
+
+        let padding = width.saturating_sub(counter.0);
+
+        if f.sign_aware_zero_pad() {
+            return options.write(f, amount, padding);
+        }
+
+        let (before, after) = match f.align() {
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(Alignment::Left) | None => (0, padding),
+        };
+        let fill = f.fill();
+
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+
+        options.write(f, amount, 0)?;
+
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The sign and magnitude of an amount of money, split into whole and fractional units.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Amount {
+    is_negative: bool,
+    units: u128,
+    fraction: u128,
+    scale: u32,
+}
+
+impl Amount {
+    /// Splits `minor_units` into whole units and `scale` digits of fractional units.
+    pub(crate) fn new(minor_units: i128, scale: u32) -> Self {
+        let magnitude = minor_units.unsigned_abs();
+        let divisor = 10_u128.pow(scale);
+
+        Self {
+            is_negative: minor_units < 0,
+            units: magnitude / divisor,
+            fraction: magnitude % divisor,
+            scale,
+        }
+    }
+}
+
+/// Counts the characters written to it, so padding can be computed without allocating.
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Dollars;
+
+    fn format(cents: i64, options: &FormatOptions) -> String {
+        Dollars::from(cents).format_with(options).to_string()
+    }
+
+    #[test]
+    fn presets() {
+        assert_eq!(format(-123456789, &FormatOptions::DEFAULT), "-$1234567.89");
+        assert_eq!(
+            format(-123456789, &FormatOptions::GROUPED),
+            "-$1,234,567.89"
+        );
+        assert_eq!(format(-123456, &FormatOptions::ACCOUNTING), "($1,234.56)");
+        assert_eq!(format(123456, &FormatOptions::ACCOUNTING), "$1,234.56");
+    }
+
+    #[test]
+    fn grouping() {
+        let options = FormatOptions::GROUPED;
+
+        assert_eq!(format(0, &options), "$0.00");
+        assert_eq!(format(99999, &options), "$999.99");
+        assert_eq!(format(100000, &options), "$1,000.00");
+        assert_eq!(format(i64::MIN, &options), "-$92,233,720,368,547,758.08");
+
+        let options = options
+            .grouping_separator(Some('.'))
+            .decimal_mark(',')
+            .group_size(4);
+        assert_eq!(format(1234567800, &options), "$1234.5678,00");
+    }
+
+    #[test]
+    fn symbol_and_sign() {
+        let options = FormatOptions::new()
+            .symbol("USD")
+            .symbol_position(SymbolPosition::Suffix)
+            .symbol_spacing(true);
+
+        assert_eq!(format(123456, &options), "1234.56 USD");
+        assert_eq!(format(-123456, &options), "-1234.56 USD");
+
+        let options = options.symbol_separator('\u{a0}');
+        assert_eq!(format(123456, &options), "1234.56\u{a0}USD");
+
+        let options = FormatOptions::new().symbol_position(SymbolPosition::Omitted);
+        assert_eq!(format(-5, &options), "-0.05");
+
+        let options = FormatOptions::new().sign_policy(SignPolicy::Always);
+        assert_eq!(format(5, &options), "+$0.05");
+        assert_eq!(format(0, &options), "+$0.00");
+        assert_eq!(format(-5, &options), "-$0.05");
+    }
+
+    #[test]
+    fn padding() {
+        let value = Dollars::from(-123456);
+        let formatted = value.format_with(&FormatOptions::ACCOUNTING);
+
+        assert_eq!(format!("{:12}", formatted), "($1,234.56) ");
+        assert_eq!(format!("{:>12}", formatted), " ($1,234.56)");
+        assert_eq!(format!("{:*^14}", formatted), "*($1,234.56)**");
+        assert_eq!(format!("{:4}", formatted), "($1,234.56)");
+    }
+
+    #[test]
+    fn flags() {
+        let value = Dollars::from(-123456);
+
+        assert_eq!(
+            format!("{:+}", (-value).format_with(&FormatOptions::GROUPED)),
+            "+$1,234.56"
+        );
+        assert_eq!(
+            format!("{:+}", value.format_with(&FormatOptions::GROUPED)),
+            "-$1,234.56"
+        );
+        assert_eq!(
+            format!("{:013}", value.format_with(&FormatOptions::ACCOUNTING)),
+            "($001,234.56)"
+        );
+        assert_eq!(
+            format!("{:<+013}", (-value).format_with(&FormatOptions::DEFAULT)),
+            "+$00001234.56"
+        );
+    }
+}
@@ -2,190 +2,483 @@
 //!
 //! See [`Dollars`] below.
 
-use std::fmt::{self, Debug, Display, Formatter};
-use std::ops::{Add, Neg, Sub};
-use std::str::FromStr;
+mod allocate;
+mod compact;
+pub mod currency;
+mod dollars128;
+pub mod exchange;
+mod float;
+mod format;
+mod locale;
+mod macros;
+mod money;
+mod parse;
+mod rate;
+mod rounding;
+mod scaled;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod sign;
+mod words;
+
+pub use compact::CompactOptions;
+pub use currency::{Currency, CurrencyInfo};
+pub use dollars128::{Dollars128, TryFromDollars128Error};
+pub use float::ConversionError;
+pub use format::{FormatOptions, Formatted, NegativeStyle, SignPolicy, SymbolPosition};
+pub use locale::Locale;
+pub use money::Money;
+pub use parse::{ParseError, ParseErrorKind, ParseOptions};
+pub use rate::{ParseRateError, Rate};
+pub use rounding::RoundingMode;
+pub use scaled::{Micros, Mills, ScaledDollars};
+pub use sign::Sign;
+pub use words::WordsStyle;
 
 /// A dollar value, backed by a single integer value in cents.
-#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Dollars {
-    cent_value: i64,
+///
+/// This is [`Money`] in [`Usd`](currency::Usd), so it has the same arithmetic, rounding, and
+/// formatting surface as every other currency, along with the cent-specific methods below, like
+/// [`from_cents`](Dollars::from_cents), [`to_words`](Dollars::to_words), and
+/// [`parse_with`](Dollars::parse_with).
+pub type Dollars = Money<currency::Usd>;
+
+/// Constructs a [`Dollars`] constant from a literal, which is parsed at compile time.
+///
+/// The literal can either be a string, which is parsed with the same grammar as the
+/// [`FromStr`](std::str::FromStr) impl, or a number of dollars with an optional two-digit cents
+/// portion:
+///
+/// ```
+/// # use dollars::{dollars, Dollars};
+/// const PRICE: Dollars = dollars!("$19.99");
+///
+/// assert_eq!(PRICE, Dollars::from(1999));
+/// assert_eq!(dollars!(19.99), PRICE);
+/// assert_eq!(dollars!(-5), Dollars::from(-500));
+/// ```
+///
+/// An invalid literal is a compile error rather than a runtime error:
+///
+/// ```compile_fail
+/// # use dollars::dollars;
+/// let price = dollars!("$19.9");
+/// ```
+#[macro_export]
+macro_rules! dollars {
+    (- $literal:literal) => {{
+        const VALUE: $crate::Dollars =
+            $crate::Dollars::__from_literal(::core::concat!("-", ::core::stringify!($literal)));
+        VALUE
+    }};
+
+    ($literal:literal) => {{
+        const VALUE: $crate::Dollars =
+            $crate::Dollars::__from_literal(::core::stringify!($literal));
+        VALUE
+    }};
 }
 
 impl Dollars {
+    /// Constructs a value from a number of cents.
+    ///
+    /// This is equivalent to the `From<i64>` impl, but can be used in constants.
+    pub const fn from_cents(cent_value: i64) -> Self {
+        Self::from_minor_units(cent_value)
+    }
+
+    /// Constructs a value from its dollars and cents portions, so `Dollars::new(12, 34)` is
+    /// `$12.34`.
+    ///
+    /// Both portions carry the sign of the value, so `Dollars::new(-12, -34)` is `-$12.34` and
+    /// `Dollars::new(0, -34)` is `-$0.34`.
+    ///
+    /// Panics if the cents portion isn't between -99 and 99, if the portions have different
+    /// signs, or if the value overflows; see [`checked_new`](Dollars::checked_new) for a
+    /// non-panicking version.
+    pub const fn new(dollars: i64, cents: i64) -> Self {
+        match Self::checked_new(dollars, cents) {
+            Some(value) => value,
+            None => panic!("invalid dollars and cents portions in Dollars::new"),
+        }
+    }
+
+    /// Checked version of [`new`](Dollars::new). Returns `None` if the cents portion isn't
+    /// between -99 and 99, if the portions have different signs, or if the value overflows.
+    pub const fn checked_new(dollars: i64, cents: i64) -> Option<Self> {
+        if cents <= -100 || cents >= 100 || dollars.signum() * cents.signum() < 0 {
+            return None;
+        }
+
+        match dollars.checked_mul(100) {
+            Some(dollars) => match dollars.checked_add(cents) {
+                Some(cent_value) => Some(Self::from_cents(cent_value)),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
     /// The dollars portion of the value.
-    pub fn dollars(&self) -> i64 {
-        (self.cent_value / 100).abs()
+    pub const fn dollars(&self) -> i64 {
+        (self.in_cents() / 100).abs()
     }
 
     /// The cents portion of the value.
-    pub fn cents(&self) -> i64 {
-        (self.cent_value % 100).abs()
+    pub const fn cents(&self) -> i64 {
+        (self.in_cents() % 100).abs()
     }
 
     /// The value in cents.
     ///
     /// Note the difference between this method and [`cents`](Dollars::cents).
-    pub fn in_cents(&self) -> i64 {
-        self.cent_value
+    pub const fn in_cents(&self) -> i64 {
+        self.minor_units()
     }
+}
 
-    /// Whether or not the value is positive.
-    pub fn is_positive(&self) -> bool {
-        self.in_cents() > 0
+impl From<i64> for Dollars {
+    fn from(cent_value: i64) -> Self {
+        Self::from_cents(cent_value)
     }
 }
 
-impl Add for Dollars {
-    type Output = Self;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    fn add(self, other: Self) -> Self::Output {
-        Self::from(self.in_cents() + other.in_cents())
+    fn parse(s: &str) -> Result<i64, ParseErrorKind> {
+        s.parse::<Dollars>()
+            .map(|d| d.in_cents())
+            .map_err(|err| err.kind())
     }
-}
 
-impl Debug for Dollars {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}", self)
+    #[test]
+    fn printing() {
+        let cases = [
+            (0, "$0.00"),
+            (5, "$0.05"),
+            (-5, "-$0.05"),
+            (100, "$1.00"),
+            (-99, "-$0.99"),
+            (123456, "$1234.56"),
+            (-123456, "-$1234.56"),
+            (i64::MAX, "$92233720368547758.07"),
+            (i64::MIN, "-$92233720368547758.08"),
+        ];
+
+        for (cents, expected) in cases {
+            assert_eq!(Dollars::from(cents).to_string(), expected);
+        }
     }
-}
 
-impl Display for Dollars {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        // Delegating the actual formatting to format!
-        // so that it plays nice with custom format
-        // specifiers. This seems stupid
-        let as_str = format!(
-            "{}${}.{:02}",
-            if self.in_cents() < 0 { "-" } else { "" },
-            self.dollars(),
-            self.cents(),
-        );
+    #[test]
+    fn parsing_normal_cases() {
+        assert_eq!(parse("12"), Ok(1200));
+        assert_eq!(parse("$12"), Ok(1200));
+        assert_eq!(parse("12.34"), Ok(1234));
+        assert_eq!(parse("$12.34"), Ok(1234));
+        assert_eq!(parse("+$12.34"), Ok(1234));
+        assert_eq!(parse("-$12.34"), Ok(-1234));
+        assert_eq!(parse("-12.34"), Ok(-1234));
+        assert_eq!(parse("$1234567.89"), Ok(123456789));
+    }
 
-        Display::fmt(&as_str, f)
+    #[test]
+    fn parsing_valid_edge_cases() {
+        assert_eq!(parse("0"), Ok(0));
+        assert_eq!(parse("-0"), Ok(0));
+        assert_eq!(parse("-$0.00"), Ok(0));
+        assert_eq!(parse("0.01"), Ok(1));
+        assert_eq!(parse("$0.99"), Ok(99));
+        assert_eq!(parse("007.00"), Ok(700));
+        assert_eq!(parse("$92233720368547758.07"), Ok(i64::MAX));
+        assert_eq!(parse("-$92233720368547758.08"), Ok(i64::MIN));
+        assert_eq!(parse("-92233720368547758"), Ok(-9223372036854775800));
     }
-}
 
-impl From<i64> for Dollars {
-    fn from(cent_value: i64) -> Self {
-        Self { cent_value }
+    #[test]
+    fn parsing_invalid_edge_cases() {
+        assert_eq!(parse(""), Err(ParseErrorKind::MissingDigits));
+        assert_eq!(parse("$"), Err(ParseErrorKind::MissingDigits));
+        assert_eq!(parse("-"), Err(ParseErrorKind::MissingDigits));
+        assert_eq!(parse("$.50"), Err(ParseErrorKind::MissingDigits));
+        assert_eq!(parse("1.5"), Err(ParseErrorKind::BadCentsLength));
+        assert_eq!(parse("1..23"), Err(ParseErrorKind::ExtraDecimalPoint));
+        assert_eq!(parse("1.2.3"), Err(ParseErrorKind::ExtraDecimalPoint));
+        assert_eq!(parse("+-1"), Err(ParseErrorKind::InvalidDigit('-')));
+        assert_eq!(parse("$-1"), Err(ParseErrorKind::InvalidDigit('-')));
+        assert_eq!(parse("$$1"), Err(ParseErrorKind::InvalidDigit('$')));
+        assert_eq!(parse(" 1"), Err(ParseErrorKind::InvalidDigit(' ')));
+        assert_eq!(parse("1,000"), Err(ParseErrorKind::InvalidDigit(',')));
+        assert_eq!(parse("1.x0"), Err(ParseErrorKind::InvalidDigit('x')));
+        assert_eq!(parse("1.0x"), Err(ParseErrorKind::InvalidDigit('x')));
+        assert_eq!(parse("€1"), Err(ParseErrorKind::NonAscii));
+    }
+
+    #[test]
+    fn parsing_rejects_trailing_input() {
+        assert_eq!(parse("1."), Err(ParseErrorKind::BadCentsLength));
+        assert_eq!(parse("1.234"), Err(ParseErrorKind::BadCentsLength));
+        assert_eq!(parse("$1.234xyz"), Err(ParseErrorKind::BadCentsLength));
+        assert_eq!(parse("$1.23xyz"), Err(ParseErrorKind::TrailingCharacters));
+        assert_eq!(parse("$1.23 "), Err(ParseErrorKind::TrailingCharacters));
+        assert_eq!(parse("1.23."), Err(ParseErrorKind::ExtraDecimalPoint));
     }
-}
 
-impl FromStr for Dollars {
-    type Err = ParseError;
-
-    // TODO: document the somewhat wonky overflow handling + the fact that it's maybe slightly
-    // more permissive than it should be
-    fn from_str(s: &str) -> Result<Self, ParseError> {
-        // there may be a +/- in front for sign
-        // there may be $ in front of the value
-        // the value may be an integer
-        // if it specifies cents, the cents value must be two digits long
-        if !s.is_ascii() {
-            return Err(ParseErrorKind::NonAscii.into());
+    #[test]
+    fn parsing_overflow() {
+        assert_eq!(parse("92233720368547758.08"), Err(ParseErrorKind::Overflow));
+        assert_eq!(
+            parse("-92233720368547758.09"),
+            Err(ParseErrorKind::Overflow)
+        );
+        assert_eq!(parse("92233720368547759"), Err(ParseErrorKind::Overflow));
+        assert_eq!(parse("-92233720368547759"), Err(ParseErrorKind::Overflow));
+        assert_eq!(
+            parse("100000000000000000000"),
+            Err(ParseErrorKind::Overflow)
+        );
+        assert_eq!(parse("0000000000000000000000001.00"), Ok(100));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut values = vec![
+            0,
+            1,
+            -1,
+            99,
+            -99,
+            100,
+            -100,
+            i64::MAX,
+            i64::MIN,
+            i64::MIN + 1,
+        ];
+        let mut state = 0x2545f4914f6cdd1d_u64;
+
+        for _ in 0..10_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            // shift by a varying amount so that every magnitude gets covered
+            values.push((state as i64) >> (state >> 58));
         }
 
-        let mut chars = s.chars().peekable();
-        let sign = match chars.peek().copied() {
-            Some('-') => {
-                chars.next();
-                -1
-            },
+        for cents in values {
+            let value = Dollars::from(cents);
+            assert_eq!(value.to_string().parse::<Dollars>(), Ok(value));
+        }
+    }
 
-            c => {
-                if c == Some('+') {
-                    chars.next();
-                }
+    #[test]
+    fn parse_error_positions() {
+        let err = "$1x.00".parse::<Dollars>().unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidDigit('x'));
+        assert_eq!(err.position(), 2);
+        assert_eq!(
+            err.to_string(),
+            "failed to parse dollars: invalid digit 'x' at byte 2"
+        );
 
-                1
-            },
-        };
+        let err = "-1.0y".parse::<Dollars>().unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidDigit('y'));
+        assert_eq!(err.position(), 4);
 
-        if let Some('$') = chars.peek().copied() {
-            chars.next();
-        }
+        let err = "1.2.3".parse::<Dollars>().unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::ExtraDecimalPoint);
+        assert_eq!(err.position(), 3);
 
-        let dollars = chars
-            .by_ref()
-            .take_while(|&c| c != '.')
-            .try_fold(0_i64, |acc, c| {
-                c.to_digit(10)
-                    .ok_or(ParseErrorKind::InvalidDigit(c))
-                    .and_then(|d| acc.checked_add(d as i64).ok_or(ParseErrorKind::Overflow))
-            })?;
-        let cents = match (chars.next(), chars.next()) {
-            (Some('.'), _) | (_, Some('.')) => return Err(ParseErrorKind::ExtraDecimalPoint.into()),
-            (Some(_), None) => return Err(ParseErrorKind::BadCentsLength.into()),
-            (None, _) => 0,
-            (Some(c1), Some(c2)) => {
-                let d1 = c1.to_digit(10).ok_or(ParseErrorKind::InvalidDigit(c1))? as i64;
-                let d2 = c2.to_digit(10).ok_or(ParseErrorKind::InvalidDigit(c1))? as i64;
-
-                d1 * 10 + d2
-            },
-        };
+        let err = "1.5".parse::<Dollars>().unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::BadCentsLength);
+        assert_eq!(err.position(), 1);
 
-        dollars
-            .checked_mul(100)
-            .and_then(|d| d.checked_add(cents))
-            .and_then(|d| d.checked_mul(sign))
-            .map(Self::from)
-            .ok_or(ParseErrorKind::Overflow.into())
+        let err = "$1€".parse::<Dollars>().unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NonAscii);
+        assert_eq!(err.position(), 2);
+
+        let err = "99999999999999999999".parse::<Dollars>().unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::Overflow);
+        assert_eq!(err.position(), 18);
     }
-}
 
-impl Neg for Dollars {
-    type Output = Self;
+    #[test]
+    fn display_flags() {
+        let value = Dollars::from(123456);
+
+        assert_eq!(format!("{}", value), "$1234.56");
+        assert_eq!(format!("{:+}", value), "+$1234.56");
+        assert_eq!(format!("{:+}", -value), "-$1234.56");
+        assert_eq!(format!("{:#}", -value), "-$1,234.56");
+        assert_eq!(format!("{:012}", value), "$00001234.56");
+        assert_eq!(format!("{:+012}", -value), "-$0001234.56");
+        assert_eq!(format!("{:>10}", Dollars::from(5)), "     $0.05");
+        assert_eq!(format!("{:?}", -value), "-$1234.56");
+    }
 
-    fn neg(self) -> Self::Output {
-        Self {
-            cent_value: -self.cent_value,
-        }
+    #[test]
+    fn const_construction() {
+        const FEE: Dollars = Dollars::new(19, 99);
+        const REFUND: Dollars = Dollars::new(0, -50);
+        const LIMIT: Dollars = Dollars::from_cents(100_000);
+
+        assert_eq!(FEE, Dollars::from(1999));
+        assert_eq!(REFUND, Dollars::from(-50));
+        assert_eq!(LIMIT.dollars(), 1000);
+        const { assert!(REFUND.is_negative()) };
+
+        assert_eq!(Dollars::new(-12, -34), Dollars::from(-1234));
+        assert_eq!(Dollars::new(-12, 0), Dollars::from(-1200));
+        assert_eq!(Dollars::checked_new(-12, 34), None);
+        assert_eq!(Dollars::checked_new(12, 100), None);
+        assert_eq!(Dollars::checked_new(i64::MAX / 100, 99), None);
+        assert_eq!(Dollars::checked_new(i64::MIN / 100, -8), Some(Dollars::MIN));
     }
-}
 
-impl Sub for Dollars {
-    type Output = Self;
+    #[test]
+    fn literals() {
+        assert_eq!(dollars!("$19.99"), Dollars::from(1999));
+        assert_eq!(dollars!("-$0.05"), Dollars::from(-5));
+        assert_eq!(dollars!(19.99), Dollars::from(1999));
+        assert_eq!(dollars!(-19.99), Dollars::from(-1999));
+        assert_eq!(dollars!(1000), Dollars::from(100000));
+        assert_eq!(dollars!("-$92233720368547758.08"), Dollars::MIN);
+    }
 
-    fn sub(self, other: Self) -> Self::Output {
-        Self::from(self.in_cents() - other.in_cents())
+    #[test]
+    fn sign_predicates() {
+        assert_eq!(Dollars::ZERO, Dollars::default());
+        assert!(Dollars::ZERO.is_zero());
+        assert!(!Dollars::ZERO.is_positive() && !Dollars::ZERO.is_negative());
+        assert_eq!(Dollars::ZERO.signum(), 0);
+        assert_eq!(Dollars::MIN.signum(), -1);
+        assert_eq!(Dollars::MAX.signum(), 1);
+        assert_eq!(Dollars::from(-150).abs(), Dollars::from(150));
+        assert_eq!(Dollars::MIN.checked_abs(), None);
+        assert_eq!(Dollars::MAX.checked_abs(), Some(Dollars::MAX));
+        assert_eq!(Dollars::MIN.unsigned_abs(), i64::MIN.unsigned_abs());
+        assert_eq!(Dollars::from(-150).unsigned_abs(), 150);
     }
-}
 
-/// Opaque error capturing a failure to parse a [`Dollars`] from a string.
-///
-/// Note that the exact failure modes for parsing are not exposed directly.
-#[derive(Clone, Debug, thiserror::Error)]
-#[error("failed to parse dollars: {0}")]
-pub struct ParseError(#[from] ParseErrorKind);
+    #[test]
+    fn checked_arithmetic() {
+        let max = Dollars::from(i64::MAX);
+        let min = Dollars::from(i64::MIN);
 
-#[derive(Clone, Debug, thiserror::Error)]
-enum ParseErrorKind {
-    #[error("invalid digit '{0}'")]
-    InvalidDigit(char),
+        assert_eq!(
+            Dollars::from(150).checked_add(Dollars::from(25)),
+            Some(Dollars::from(175))
+        );
+        assert_eq!(max.checked_add(Dollars::from(1)), None);
+        assert_eq!(
+            Dollars::from(150).checked_sub(Dollars::from(175)),
+            Some(Dollars::from(-25))
+        );
+        assert_eq!(min.checked_sub(Dollars::from(1)), None);
+        assert_eq!(Dollars::from(150).checked_neg(), Some(Dollars::from(-150)));
+        assert_eq!(min.checked_neg(), None);
+    }
 
-    #[error("value overflows")]
-    Overflow,
+    #[test]
+    fn saturating_arithmetic() {
+        let max = Dollars::from(i64::MAX);
+        let min = Dollars::from(i64::MIN);
 
-    #[error("cents must be two digits long")]
-    BadCentsLength,
+        assert_eq!(max.saturating_add(Dollars::from(1)), max);
+        assert_eq!(min.saturating_sub(Dollars::from(1)), min);
+        assert_eq!(min.saturating_neg(), max);
+    }
 
-    #[error("too many decimal points")]
-    ExtraDecimalPoint,
+    #[test]
+    fn wrapping_and_overflowing_arithmetic() {
+        let max = Dollars::from(i64::MAX);
+        let min = Dollars::from(i64::MIN);
+
+        assert_eq!(max.wrapping_add(Dollars::from(1)), min);
+        assert_eq!(min.wrapping_sub(Dollars::from(1)), max);
+        assert_eq!(min.wrapping_neg(), min);
+        assert_eq!(max.overflowing_add(Dollars::from(1)), (min, true));
+        assert_eq!(min.overflowing_sub(Dollars::from(1)), (max, true));
+        assert_eq!(min.overflowing_neg(), (min, true));
+        assert_eq!(
+            Dollars::from(5).overflowing_add(Dollars::from(5)),
+            (Dollars::from(10), false)
+        );
+    }
 
-    #[error("non-ASCII strings are not allowed")]
-    NonAscii,
-}
+    #[test]
+    fn assign_arithmetic() {
+        let mut total = Dollars::from(100);
+        total += Dollars::from(250);
+        total -= Dollars::from(75);
 
-#[cfg(test)]
-mod tests {
-    // sanity check the entire api
-    // for printing:
-    // for parsing:
-    // sanity check some normal cases:
-    // valid edge cases:
-    // invalid edge cases:
-    // slightly over-permissive cases:
-    // weird overflow cases:
+        assert_eq!(total, Dollars::from(275));
+    }
+
+    #[test]
+    fn sums() {
+        let prices = [Dollars::from(1999), Dollars::from(501), Dollars::from(-500)];
+
+        assert_eq!(prices.iter().sum::<Dollars>(), Dollars::from(2000));
+        assert_eq!(prices.into_iter().sum::<Dollars>(), Dollars::from(2000));
+        assert_eq!(
+            std::iter::empty::<Dollars>().sum::<Dollars>(),
+            Dollars::default()
+        );
+
+        assert_eq!(Dollars::checked_sum(prices), Some(Dollars::from(2000)));
+        assert_eq!(
+            Dollars::checked_sum([Dollars::from(i64::MAX), Dollars::from(1)]),
+            None
+        );
+    }
+
+    #[test]
+    fn scalar_arithmetic() {
+        assert_eq!(Dollars::from(1999) * 3, Dollars::from(5997));
+        assert_eq!(3 * Dollars::from(1999), Dollars::from(5997));
+        assert_eq!(Dollars::from(1000) / 3, Dollars::from(333));
+        assert_eq!(Dollars::from(-1000) / 3, Dollars::from(-333));
+        assert_eq!(Dollars::from(1000) % 3, Dollars::from(1));
+
+        assert_eq!(Dollars::from(i64::MAX).checked_mul(2), None);
+        assert_eq!(Dollars::from(1000).checked_div(0), None);
+        assert_eq!(Dollars::from(i64::MIN).checked_div(-1), None);
+        assert_eq!(Dollars::from(1000).checked_rem(0), None);
+    }
+
+    #[test]
+    fn scalar_division_with_rounding() {
+        let ten = Dollars::from(1000);
+
+        assert_eq!(ten.div_round(3, RoundingMode::Truncate), Dollars::from(333));
+        assert_eq!(ten.div_round(3, RoundingMode::Ceil), Dollars::from(334));
+        assert_eq!(
+            (-ten).div_round(3, RoundingMode::Floor),
+            Dollars::from(-334)
+        );
+        assert_eq!(
+            Dollars::from(5).div_round(2, RoundingMode::HalfEven),
+            Dollars::from(2)
+        );
+        assert_eq!(
+            Dollars::from(5).div_round(2, RoundingMode::HalfUp),
+            Dollars::from(3)
+        );
+        assert_eq!(
+            Dollars::from(-5).div_round(2, RoundingMode::HalfUp),
+            Dollars::from(-2)
+        );
+        assert_eq!(
+            Dollars::from(-5).div_round(2, RoundingMode::HalfAwayFromZero),
+            Dollars::from(-3)
+        );
+
+        assert_eq!(ten.checked_div_round(0, RoundingMode::Truncate), None);
+        assert_eq!(
+            Dollars::from(i64::MIN).checked_div_round(-1, RoundingMode::Truncate),
+            None
+        );
+    }
 }
@@ -0,0 +1,170 @@
+use crate::{Dollars, FormatOptions, Formatted, ParseOptions, SymbolPosition};
+
+/// The conventions for formatting dollar amounts in a particular locale, like `1 234,56 $` in
+/// Canadian French.
+///
+/// The conventions come from an embedded table, so no locale data has to be installed or
+/// downloaded. Use [`Dollars::format_locale`] to format with them:
+///
+/// ```
+/// # use dollars::{Dollars, Locale};
+/// let value = Dollars::from(-123456);
+///
+/// assert_eq!(value.format_locale(&Locale::EN_CA).to_string(), "-$1,234.56");
+/// assert_eq!(value.format_locale(&Locale::FR_CA).to_string(), "-1\u{a0}234,56\u{a0}$");
+/// ```
+///
+/// The conventions are just [`FormatOptions`], so they can be adjusted further with
+/// [`format_options`](Locale::format_options), like for accounting-style negatives.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Locale {
+    tag: &'static str,
+    options: FormatOptions,
+}
+
+/// A prefixed `$` with comma grouping and a decimal point, like `$1,234.56`.
+const NORTH_AMERICAN: FormatOptions = FormatOptions::GROUPED;
+
+/// A suffixed `$` after a non-breaking space, with a comma as the decimal mark.
+const EUROPEAN: FormatOptions = FormatOptions::new()
+    .symbol_position(SymbolPosition::Suffix)
+    .symbol_spacing(true)
+    .symbol_separator('\u{a0}')
+    .decimal_mark(',');
+
+impl Locale {
+    /// Every locale in the table, in alphabetical order of tag.
+    pub const ALL: &'static [Self] = &[
+        Self::DE_DE,
+        Self::EN_CA,
+        Self::EN_US,
+        Self::ES_MX,
+        Self::ES_US,
+        Self::FR_CA,
+        Self::FR_FR,
+        Self::PT_BR,
+    ];
+    /// German (Germany), like `1.234,56 $`.
+    pub const DE_DE: Self = Self::new("de-DE", EUROPEAN.grouping_separator(Some('.')));
+    /// English (Canada), like `$1,234.56`.
+    pub const EN_CA: Self = Self::new("en-CA", NORTH_AMERICAN);
+    /// English (United States), like `$1,234.56`.
+    pub const EN_US: Self = Self::new("en-US", NORTH_AMERICAN);
+    /// Spanish (Mexico), like `$1,234.56`.
+    pub const ES_MX: Self = Self::new("es-MX", NORTH_AMERICAN);
+    /// Spanish (United States), like `$1,234.56`.
+    pub const ES_US: Self = Self::new("es-US", NORTH_AMERICAN);
+    /// French (Canada), like `1 234,56 $`, with non-breaking spaces.
+    pub const FR_CA: Self = Self::new("fr-CA", EUROPEAN.grouping_separator(Some('\u{a0}')));
+    /// French (France), like `1 234,56 $`, with narrow non-breaking spaces between groups.
+    pub const FR_FR: Self = Self::new("fr-FR", EUROPEAN.grouping_separator(Some('\u{202f}')));
+    /// Portuguese (Brazil), like `US$ 1.234,56`, with a non-breaking space.
+    pub const PT_BR: Self = Self::new(
+        "pt-BR",
+        EUROPEAN
+            .symbol("US$")
+            .symbol_position(SymbolPosition::Prefix)
+            .grouping_separator(Some('.')),
+    );
+
+    const fn new(tag: &'static str, options: FormatOptions) -> Self {
+        Self { tag, options }
+    }
+
+    /// Looks up a locale in the table by its language tag, like `fr-CA`, ignoring case. POSIX
+    /// style tags with underscores, like `fr_CA`, are accepted too.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|locale| {
+                locale.tag.len() == tag.len()
+                    && locale
+                        .tag
+                        .bytes()
+                        .zip(tag.bytes())
+                        .all(|(a, b)| a.eq_ignore_ascii_case(&b) || (a == b'-' && b == b'_'))
+            })
+            .copied()
+    }
+
+    /// The language tag, like `fr-CA`.
+    pub const fn tag(&self) -> &'static str {
+        self.tag
+    }
+
+    /// The locale's conventions, as options for [`format_with`](Dollars::format_with).
+    pub const fn format_options(&self) -> FormatOptions {
+        self.options
+    }
+
+    /// Options for parsing values in the locale's conventions, as
+    /// [`parse_locale`](Dollars::parse_locale) does.
+    pub const fn parse_options(&self) -> ParseOptions {
+        self.options.parse_options()
+    }
+}
+
+impl Dollars {
+    /// Formats the value according to the conventions of `locale`.
+    ///
+    /// The [`Display`](std::fmt::Display) impl is unaffected, and always uses the plain
+    /// `-$1234.56` form.
+    pub fn format_locale(self, locale: &Locale) -> Formatted<'_> {
+        self.format_with(&locale.options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formatting() {
+        let format = |cents: i64, locale: &Locale| {
+            Dollars::from(cents)
+                .format_locale(locale)
+                .to_string()
+                .replace(['\u{a0}', '\u{202f}'], "_")
+        };
+
+        assert_eq!(format(-123456789, &Locale::EN_US), "-$1,234,567.89");
+        assert_eq!(format(123456789, &Locale::EN_CA), "$1,234,567.89");
+        assert_eq!(format(123456789, &Locale::ES_MX), "$1,234,567.89");
+        assert_eq!(format(123456789, &Locale::ES_US), "$1,234,567.89");
+        assert_eq!(format(-123456789, &Locale::FR_CA), "-1_234_567,89_$");
+        assert_eq!(format(123456789, &Locale::FR_FR), "1_234_567,89_$");
+        assert_eq!(format(-123456789, &Locale::DE_DE), "-1.234.567,89_$");
+        assert_eq!(format(123456789, &Locale::PT_BR), "US$_1.234.567,89");
+        assert_eq!(format(5, &Locale::FR_CA), "0,05_$");
+    }
+
+    #[test]
+    fn lookups() {
+        assert_eq!(Locale::from_tag("fr-CA"), Some(Locale::FR_CA));
+        assert_eq!(Locale::from_tag("FR_ca"), Some(Locale::FR_CA));
+        assert_eq!(Locale::from_tag("fr"), None);
+        assert_eq!(Locale::from_tag("en-GB"), None);
+
+        for pair in Locale::ALL.windows(2) {
+            assert!(pair[0].tag() < pair[1].tag());
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for locale in Locale::ALL {
+            for cents in [0, 5, -123456789, i64::MAX, i64::MIN] {
+                let value = Dollars::from(cents);
+                let formatted = value.format_locale(locale).to_string();
+
+                assert_eq!(
+                    Dollars::parse_locale(&formatted, locale),
+                    Ok(value),
+                    "{} in {}",
+                    formatted,
+                    locale.tag()
+                );
+            }
+        }
+    }
+}
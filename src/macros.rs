@@ -0,0 +1,286 @@
+/// Implements the arithmetic, summing, and formatting surface shared by every amount type, so
+/// that [`Money`](crate::Money) (and so [`Dollars`](crate::Dollars)),
+/// [`Dollars128`](crate::Dollars128), and [`ScaledDollars`](crate::ScaledDollars) all stay in sync.
+///
+/// The type is described by its generic parameters, its backing integer type and the unsigned
+/// version of it, a const constructor from and accessor for that integer, and the
+/// [`FormatOptions`](crate::FormatOptions) its [`Display`](std::fmt::Display) impl uses, which get
+/// grouped thousands with the `#` flag. Attributes before `display` are put on the
+/// [`Display`](std::fmt::Display) impl.
+macro_rules! impl_amount {
+    (
+        impl[$($generics:tt)*] $ty:ty {
+            repr: ($int:ty, $uint:ty),
+            from: $from:ident,
+            get: $get:ident,
+            $(#[$display_attr:meta])*
+            display: $display:expr $(,)?
+        }
+    ) => {
+        impl<$($generics)*> $ty {
+            /// Whether or not the value is positive.
+            pub const fn is_positive(&self) -> bool {
+                self.$get() > 0
+            }
+
+            /// Whether or not the value is negative.
+            pub const fn is_negative(&self) -> bool {
+                self.$get() < 0
+            }
+
+            /// Whether or not the value is zero.
+            pub const fn is_zero(&self) -> bool {
+                self.$get() == 0
+            }
+
+            /// The sign of the value: -1 if it's negative, 0 if it's zero, and 1 if it's positive.
+            pub const fn signum(&self) -> $int {
+                self.$get().signum()
+            }
+
+            /// The absolute value.
+            ///
+            /// Like the integer `abs`, this overflows for the minimum representable value; see
+            /// [`checked_abs`](Self::checked_abs) for a non-overflowing version.
+            pub const fn abs(self) -> Self {
+                Self::$from(self.$get().abs())
+            }
+
+            /// Checked absolute value. Returns `None` for the minimum representable value.
+            pub const fn checked_abs(self) -> Option<Self> {
+                match self.$get().checked_abs() {
+                    Some(value) => Some(Self::$from(value)),
+                    None => None,
+                }
+            }
+
+            /// The absolute value in the smallest unit, like cents, which can't overflow, unlike
+            /// [`abs`](Self::abs).
+            pub const fn unsigned_abs(self) -> $uint {
+                self.$get().unsigned_abs()
+            }
+
+            /// Checked addition. Returns `None` if the result overflows.
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                self.$get().checked_add(other.$get()).map(Self::$from)
+            }
+
+            /// Checked subtraction. Returns `None` if the result overflows.
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                self.$get().checked_sub(other.$get()).map(Self::$from)
+            }
+
+            /// Checked negation. Returns `None` if the value is the minimum representable value.
+            pub fn checked_neg(self) -> Option<Self> {
+                self.$get().checked_neg().map(Self::$from)
+            }
+
+            /// Saturating addition. Clamps the result to the representable range instead of
+            /// overflowing.
+            pub fn saturating_add(self, other: Self) -> Self {
+                Self::$from(self.$get().saturating_add(other.$get()))
+            }
+
+            /// Saturating subtraction. Clamps the result to the representable range instead of
+            /// overflowing.
+            pub fn saturating_sub(self, other: Self) -> Self {
+                Self::$from(self.$get().saturating_sub(other.$get()))
+            }
+
+            /// Saturating negation. Negating the minimum representable value yields the maximum.
+            pub fn saturating_neg(self) -> Self {
+                Self::$from(self.$get().saturating_neg())
+            }
+
+            /// Wrapping addition. Wraps around at the boundary of the representable range.
+            pub fn wrapping_add(self, other: Self) -> Self {
+                Self::$from(self.$get().wrapping_add(other.$get()))
+            }
+
+            /// Wrapping subtraction. Wraps around at the boundary of the representable range.
+            pub fn wrapping_sub(self, other: Self) -> Self {
+                Self::$from(self.$get().wrapping_sub(other.$get()))
+            }
+
+            /// Wrapping negation. Negating the minimum representable value yields itself.
+            pub fn wrapping_neg(self) -> Self {
+                Self::$from(self.$get().wrapping_neg())
+            }
+
+            /// Overflowing addition. Returns the wrapped result along with whether or not it
+            /// overflowed.
+            pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+                let (value, overflowed) = self.$get().overflowing_add(other.$get());
+                (Self::$from(value), overflowed)
+            }
+
+            /// Overflowing subtraction. Returns the wrapped result along with whether or not it
+            /// overflowed.
+            pub fn overflowing_sub(self, other: Self) -> (Self, bool) {
+                let (value, overflowed) = self.$get().overflowing_sub(other.$get());
+                (Self::$from(value), overflowed)
+            }
+
+            /// Overflowing negation. Returns the wrapped result along with whether or not it
+            /// overflowed.
+            pub fn overflowing_neg(self) -> (Self, bool) {
+                let (value, overflowed) = self.$get().overflowing_neg();
+                (Self::$from(value), overflowed)
+            }
+
+            /// Checked scalar multiplication. Returns `None` if the result overflows.
+            pub fn checked_mul(self, rhs: $int) -> Option<Self> {
+                self.$get().checked_mul(rhs).map(Self::$from)
+            }
+
+            /// Checked scalar division, truncating toward zero like the
+            /// [`Div`](std::ops::Div) impl does.
+            ///
+            /// Returns `None` if `rhs` is zero or the result overflows.
+            pub fn checked_div(self, rhs: $int) -> Option<Self> {
+                self.$get().checked_div(rhs).map(Self::$from)
+            }
+
+            /// Checked scalar remainder. Returns `None` if `rhs` is zero or the result overflows.
+            pub fn checked_rem(self, rhs: $int) -> Option<Self> {
+                self.$get().checked_rem(rhs).map(Self::$from)
+            }
+
+            /// Scalar division, rounding the result to a whole number of the smallest unit
+            /// according to `mode`. For example, `$10.00 / 3` is `$3.33` with
+            /// [`RoundingMode::HalfEven`](crate::RoundingMode::HalfEven), and `$3.34` with
+            /// [`RoundingMode::Ceil`](crate::RoundingMode::Ceil).
+            ///
+            /// Panics if `rhs` is zero or the result overflows; see
+            /// [`checked_div_round`](Self::checked_div_round) for a non-panicking version.
+            pub fn div_round(self, rhs: $int, mode: $crate::RoundingMode) -> Self {
+                self.checked_div_round(rhs, mode)
+                    .expect("division by zero or overflow in div_round")
+            }
+
+            /// Checked scalar division with rounding. Returns `None` if `rhs` is zero or the
+            /// result overflows.
+            pub fn checked_div_round(self, rhs: $int, mode: $crate::RoundingMode) -> Option<Self> {
+                if rhs == 0 || (self.$get() == <$int>::MIN && rhs == -1) {
+                    return None;
+                }
+
+                let quotient =
+                    $crate::rounding::div_round(i128::from(self.$get()), i128::from(rhs), mode);
+                <$int>::try_from(quotient).ok().map(Self::$from)
+            }
+
+            /// Checked sum of an iterator of values. Returns `None` if the sum overflows at any
+            /// point.
+            pub fn checked_sum<I: IntoIterator<Item = Self>>(iter: I) -> Option<Self> {
+                iter.into_iter()
+                    .try_fold(Self::ZERO, |acc, value| acc.checked_add(value))
+            }
+        }
+
+        impl<$($generics)*> ::std::ops::Add for $ty {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self::Output {
+                Self::$from(self.$get() + other.$get())
+            }
+        }
+
+        impl<$($generics)*> ::std::ops::AddAssign for $ty {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<$($generics)*> ::std::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, "{}", self)
+            }
+        }
+
+        $(#[$display_attr])*
+        impl<$($generics)*> ::std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                let options: $crate::FormatOptions = $display;
+                let options = if f.alternate() {
+                    options.grouping_separator(Some(','))
+                } else {
+                    options
+                };
+
+                ::std::fmt::Display::fmt(&self.format_with(&options), f)
+            }
+        }
+
+        /// Scalar division, truncating toward zero.
+        ///
+        /// Use `div_round` to pick a different rounding mode.
+        impl<$($generics)*> ::std::ops::Div<$int> for $ty {
+            type Output = Self;
+
+            fn div(self, rhs: $int) -> Self::Output {
+                Self::$from(self.$get() / rhs)
+            }
+        }
+
+        impl<$($generics)*> ::std::ops::Mul<$int> for $ty {
+            type Output = Self;
+
+            fn mul(self, rhs: $int) -> Self::Output {
+                Self::$from(self.$get() * rhs)
+            }
+        }
+
+        impl<$($generics)*> ::std::ops::Mul<$ty> for $int {
+            type Output = $ty;
+
+            fn mul(self, rhs: $ty) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl<$($generics)*> ::std::ops::Neg for $ty {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self::$from(-self.$get())
+            }
+        }
+
+        impl<$($generics)*> ::std::ops::Rem<$int> for $ty {
+            type Output = Self;
+
+            fn rem(self, rhs: $int) -> Self::Output {
+                Self::$from(self.$get() % rhs)
+            }
+        }
+
+        impl<$($generics)*> ::std::ops::Sub for $ty {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self::Output {
+                Self::$from(self.$get() - other.$get())
+            }
+        }
+
+        impl<$($generics)*> ::std::ops::SubAssign for $ty {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<$($generics)*> ::std::iter::Sum for $ty {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, ::std::ops::Add::add)
+            }
+        }
+
+        impl<'a, $($generics)*> ::std::iter::Sum<&'a $ty> for $ty {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_amount;
@@ -0,0 +1,222 @@
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::currency::{Currency, CurrencyInfo};
+use crate::format::{Amount, Formatted};
+use crate::macros::impl_amount;
+use crate::{parse, FormatOptions, ParseError};
+
+/// An amount of money in the currency `C`, backed by a single integer value in minor units.
+///
+/// The currency is part of the type, so amounts in different currencies can't be mixed up by
+/// accident: adding a `Money<Eur>` to a `Money<Usd>` doesn't compile. Use the marker types in
+/// the [`currency`](crate::currency) module to pick a currency.
+///
+/// The minor unit depends on the currency's [exponent](CurrencyInfo::exponent): it's a cent for
+/// `USD`, a whole yen for `JPY`, and a thousandth of a dinar (a fils) for `KWD`.
+/// [`Dollars`](crate::Dollars) is `Money<Usd>`, with some extra methods that only make sense for
+/// cents.
+///
+/// ```compile_fail
+/// use dollars::currency::{Eur, Usd};
+/// use dollars::Money;
+///
+/// let total = Money::<Usd>::from_minor_units(100) + Money::<Eur>::from_minor_units(100);
+/// ```
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Money<C: Currency> {
+    minor_units: i64,
+    currency: PhantomData<C>,
+}
+
+impl<C: Currency> Money<C> {
+    /// The largest representable amount.
+    pub const MAX: Self = Self::from_minor_units(i64::MAX);
+    /// The smallest representable amount.
+    pub const MIN: Self = Self::from_minor_units(i64::MIN);
+    /// Zero.
+    pub const ZERO: Self = Self::from_minor_units(0);
+
+    /// Constructs an amount from a number of minor units.
+    pub const fn from_minor_units(minor_units: i64) -> Self {
+        Self {
+            minor_units,
+            currency: PhantomData,
+        }
+    }
+
+    /// The currency of the amount.
+    pub const fn currency(&self) -> CurrencyInfo {
+        C::INFO
+    }
+
+    /// The amount in minor units.
+    pub const fn minor_units(&self) -> i64 {
+        self.minor_units
+    }
+
+    /// The major units portion of the amount, like [`Dollars::dollars`](crate::Dollars::dollars).
+    pub const fn major(&self) -> i64 {
+        (self.minor_units / Self::minor_per_major()).abs()
+    }
+
+    /// The minor units portion of the amount, like [`Dollars::cents`](crate::Dollars::cents).
+    pub const fn minor(&self) -> i64 {
+        (self.minor_units % Self::minor_per_major()).abs()
+    }
+
+    /// Formats the amount according to `options`.
+    ///
+    /// The returned value implements [`Display`](std::fmt::Display), so it can be used directly
+    /// in `format!` and friends without allocating. Note that the symbol comes from `options`, not
+    /// from the currency.
+    pub fn format_with(self, options: &FormatOptions) -> Formatted<'_> {
+        Formatted::new(
+            Amount::new(self.minor_units.into(), C::INFO.exponent()),
+            options,
+        )
+    }
+
+    const fn minor_per_major() -> i64 {
+        10_i64.pow(C::INFO.exponent())
+    }
+}
+
+impl_amount! {
+    impl[C: Currency] Money<C> {
+        repr: (i64, u64),
+        from: from_minor_units,
+        get: minor_units,
+        /// Formats the amount with the currency's symbol, like `-$1234.56`, `€12.34` or `-¥1234`.
+        ///
+        /// Standard formatting flags are respected, without allocating. The `+` flag always shows a
+        /// sign, like `+$1234.56`; the `0` flag pads with zeros after the symbol, like `$001234.56`;
+        /// and the `#` flag groups thousands, like `-$1,234.56`. Use
+        /// [`format_with`](Money::format_with) for anything more custom.
+        display: FormatOptions::new().symbol(C::INFO.symbol()),
+    }
+}
+
+/// Parses an amount in the format produced by the [`Display`](std::fmt::Display) impl.
+///
+/// The grammar is an optional `+` or `-` sign, an optional currency symbol, at least one digit of
+/// major units, and then optionally a decimal point followed by exactly as many digits of minor
+/// units as the currency's exponent; for currencies with an exponent of 0, there's no decimal
+/// point at all. Nothing else is accepted, so for example `$1.5` and `1.234` are both errors for
+/// [`Dollars`](crate::Dollars); see [`parse_with`](crate::Dollars::parse_with) for a more lenient
+/// alternative.
+///
+/// Every amount in the representable range can be parsed, including [`i64::MIN`] minor units, so
+/// parsing the [`Display`](std::fmt::Display) output of any amount gives back the same amount.
+impl<C: Currency> FromStr for Money<C> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        parse::parse_decimal(s, C::INFO.symbol(), C::INFO.exponent()).map(Self::from_minor_units)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::currency::{Eur, Jpy, Kwd, Usd};
+    use crate::{Dollars, ParseErrorKind, RoundingMode};
+
+    #[test]
+    fn display() {
+        assert_eq!(Money::<Eur>::from_minor_units(1234).to_string(), "€12.34");
+        assert_eq!(Money::<Jpy>::from_minor_units(-1234).to_string(), "-¥1234");
+        assert_eq!(Money::<Kwd>::from_minor_units(1234).to_string(), "KD1.234");
+        assert_eq!(
+            format!("{:#}", Money::<Jpy>::from_minor_units(1234567)),
+            "¥1,234,567"
+        );
+        assert_eq!(
+            format!("{:>8}", Money::<Eur>::from_minor_units(5)),
+            "   €0.05"
+        );
+    }
+
+    #[test]
+    fn parsing() {
+        let parse_eur = |s: &str| s.parse::<Money<Eur>>().map(|m| m.minor_units());
+        let parse_jpy = |s: &str| s.parse::<Money<Jpy>>().map(|m| m.minor_units());
+        let parse_kwd = |s: &str| s.parse::<Money<Kwd>>().map(|m| m.minor_units());
+
+        assert_eq!(parse_eur("€12.34"), Ok(1234));
+        assert_eq!(parse_eur("-12.34"), Ok(-1234));
+        assert_eq!(parse_jpy("-¥1234"), Ok(-1234));
+        assert_eq!(parse_kwd("KD1.234"), Ok(1234));
+        assert_eq!(parse_kwd("5"), Ok(5000));
+
+        assert_eq!(
+            parse_eur("$12.34").unwrap_err().kind(),
+            ParseErrorKind::InvalidDigit('$')
+        );
+        assert_eq!(
+            parse_jpy("¥12.34").unwrap_err().kind(),
+            ParseErrorKind::BadCentsLength
+        );
+        assert_eq!(
+            parse_kwd("1.23").unwrap_err().kind(),
+            ParseErrorKind::BadCentsLength
+        );
+    }
+
+    #[test]
+    fn parts_and_arithmetic() {
+        let amount = Money::<Kwd>::from_minor_units(-12345);
+
+        assert_eq!((amount.major(), amount.minor()), (12, 345));
+        assert_eq!(amount.currency().code(), "KWD");
+        assert_eq!(amount + amount, Money::from_minor_units(-24690));
+        assert_eq!(amount * 2 - amount, amount);
+        assert_eq!(
+            Money::<Kwd>::MAX.checked_add(Money::from_minor_units(1)),
+            None
+        );
+        assert_eq!([amount, -amount, amount].iter().sum::<Money<Kwd>>(), amount);
+    }
+
+    #[test]
+    fn division_and_allocation() {
+        let yen = |amounts: Vec<Money<Jpy>>| {
+            amounts
+                .into_iter()
+                .map(|m| m.minor_units())
+                .collect::<Vec<_>>()
+        };
+        let amount = Money::<Jpy>::from_minor_units(1000);
+
+        assert_eq!(amount / 3, Money::from_minor_units(333));
+        assert_eq!(amount % 3, Money::from_minor_units(1));
+        assert_eq!(3 * amount, amount * 3);
+        assert_eq!(
+            amount.div_round(3, RoundingMode::Ceil),
+            Money::from_minor_units(334)
+        );
+        assert_eq!(amount.checked_div_round(0, RoundingMode::HalfEven), None);
+        assert_eq!(amount.checked_div(0), None);
+        assert_eq!(yen(amount.split(3)), [334, 333, 333]);
+        assert_eq!(yen(amount.allocate(&[1, 3])), [250, 750]);
+        assert_eq!(
+            amount.apply_rate("8.875%".parse().unwrap(), RoundingMode::HalfEven),
+            Money::from_minor_units(89)
+        );
+        assert_eq!(
+            Money::<Kwd>::from_minor_units(-1234)
+                .round_to(Money::from_minor_units(50), RoundingMode::Floor),
+            Money::from_minor_units(-1250)
+        );
+    }
+
+    #[test]
+    fn dollars_are_usd() {
+        let money = Money::<Usd>::from_minor_units(-1999);
+
+        assert_eq!(money, Dollars::from(-1999));
+        assert_eq!(money.to_string(), "-$19.99");
+        assert_eq!("-$19.99".parse::<Money<Usd>>(), Ok(money));
+        assert_eq!(money.in_cents(), money.minor_units());
+    }
+}
@@ -0,0 +1,820 @@
+use crate::{Dollars, Locale, SymbolPosition};
+
+/// Options controlling which non-standard inputs [`parse_with`](Dollars::parse_with) accepts.
+///
+/// The options are built up from [`ParseOptions::new`], which is exactly as strict as the
+/// [`FromStr`](std::str::FromStr) impl for [`Dollars`]. Each leniency can be turned on
+/// separately:
+///
+/// ```
+/// # use dollars::{Dollars, ParseOptions};
+/// let options = ParseOptions::new()
+///     .grouping_separator(Some(','))
+///     .allow_parentheses(true);
+///
+/// assert_eq!(Dollars::parse_with("($1,234.56)", &options).unwrap(), Dollars::from(-123456));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseOptions {
+    symbol: &'static str,
+    symbol_position: SymbolPosition,
+    decimal_mark: char,
+    grouping_separator: Option<char>,
+    allow_symbol_spacing: bool,
+    allow_whitespace: bool,
+    allow_parentheses: bool,
+    allow_trailing_minus: bool,
+    allow_short_cents: bool,
+    allow_currency_code: bool,
+}
+
+impl ParseOptions {
+    /// Accepts every supported leniency, with `,` as the grouping separator.
+    pub const LENIENT: Self = Self::new()
+        .grouping_separator(Some(','))
+        .allow_symbol_spacing(true)
+        .allow_whitespace(true)
+        .allow_parentheses(true)
+        .allow_trailing_minus(true)
+        .allow_short_cents(true)
+        .allow_currency_code(true);
+    /// Accepts exactly what the [`FromStr`](std::str::FromStr) impl does.
+    pub const STRICT: Self = Self::new();
+
+    /// Accepts exactly what the [`FromStr`](std::str::FromStr) impl does.
+    pub const fn new() -> Self {
+        Self {
+            symbol: "$",
+            symbol_position: SymbolPosition::Prefix,
+            decimal_mark: '.',
+            grouping_separator: None,
+            allow_symbol_spacing: false,
+            allow_whitespace: false,
+            allow_parentheses: false,
+            allow_trailing_minus: false,
+            allow_short_cents: false,
+            allow_currency_code: false,
+        }
+    }
+
+    /// Sets the currency symbol that may appear with the value. Defaults to `$`.
+    pub const fn symbol(mut self, symbol: &'static str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Sets where the currency symbol may appear, like `12.00 $` for [`SymbolPosition::Suffix`].
+    /// The symbol is always optional. Defaults to [`SymbolPosition::Prefix`].
+    pub const fn symbol_position(mut self, symbol_position: SymbolPosition) -> Self {
+        self.symbol_position = symbol_position;
+        self
+    }
+
+    /// Sets the character separating dollars from cents, like `,` in `12,50`. Defaults to `.`.
+    pub const fn decimal_mark(mut self, decimal_mark: char) -> Self {
+        self.decimal_mark = decimal_mark;
+        self
+    }
+
+    /// Sets the character that may separate groups of three digits in the dollars portion, like
+    /// `1,234,567.89`, or `None` to not accept grouping. Defaults to `None`.
+    ///
+    /// Separators are only accepted in the right places, so `1,23.45` is rejected. If the
+    /// separator is a space, a non-breaking space (U+00A0), or a narrow non-breaking space
+    /// (U+202F), then any of the three is accepted.
+    pub const fn grouping_separator(mut self, grouping_separator: Option<char>) -> Self {
+        self.grouping_separator = grouping_separator;
+        self
+    }
+
+    /// Sets whether or not one space may separate the currency symbol from the number, like
+    /// `US$ 12.00`. As with grouping, a non-breaking space is accepted too. Defaults to `false`.
+    pub const fn allow_symbol_spacing(mut self, allow_symbol_spacing: bool) -> Self {
+        self.allow_symbol_spacing = allow_symbol_spacing;
+        self
+    }
+
+    /// Sets whether or not leading and trailing whitespace is ignored. Defaults to `false`.
+    pub const fn allow_whitespace(mut self, allow_whitespace: bool) -> Self {
+        self.allow_whitespace = allow_whitespace;
+        self
+    }
+
+    /// Sets whether or not negative values may be written in parentheses, like `($45.00)`.
+    /// Defaults to `false`.
+    pub const fn allow_parentheses(mut self, allow_parentheses: bool) -> Self {
+        self.allow_parentheses = allow_parentheses;
+        self
+    }
+
+    /// Sets whether or not negative values may be written with a trailing minus sign, like
+    /// `45.00-` or `45.00 -`. Defaults to `false`.
+    pub const fn allow_trailing_minus(mut self, allow_trailing_minus: bool) -> Self {
+        self.allow_trailing_minus = allow_trailing_minus;
+        self
+    }
+
+    /// Sets whether or not the cents portion may be a single digit, like `12.5` for `$12.50`.
+    /// Defaults to `false`.
+    pub const fn allow_short_cents(mut self, allow_short_cents: bool) -> Self {
+        self.allow_short_cents = allow_short_cents;
+        self
+    }
+
+    /// Sets whether or not a `USD` currency code is accepted before or after the value, like
+    /// `USD 12.00` or `12.00 USD`. At most one space may separate the code from the value.
+    /// Defaults to `false`.
+    pub const fn allow_currency_code(mut self, allow_currency_code: bool) -> Self {
+        self.allow_currency_code = allow_currency_code;
+        self
+    }
+
+    /// Rewrites `input` into the strict grammar accepted by the [`FromStr`](std::str::FromStr) impl.
+    fn normalize<'a>(&self, input: &'a str) -> Result<Normalized<'a>, ParseError> {
+        // the leniencies are peeled off from the outside in:
+        //   surrounding whitespace
+        //   parentheses
+        //   a currency code before or after the value
+        //   a trailing minus
+        //   the sign and currency symbol
+        // and then the remaining digits are checked and rewritten
+        let mut s = input;
+        let mut is_negative = false;
+
+        if self.allow_whitespace {
+            s = s.trim();
+        }
+
+        if self.allow_parentheses {
+            if let Some(inner) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+                s = inner;
+                is_negative = true;
+            }
+        }
+
+        if self.allow_currency_code {
+            if let Some(rest) = s.strip_prefix("USD") {
+                s = rest.strip_prefix(is_space).unwrap_or(rest);
+            } else if let Some(rest) = s.strip_suffix("USD") {
+                s = rest.strip_suffix(is_space).unwrap_or(rest);
+            }
+        }
+
+        let mut normalized = Normalized::new(input);
+
+        if self.allow_trailing_minus {
+            if let Some(rest) = s.strip_suffix('-') {
+                let rest = rest.strip_suffix(is_space).unwrap_or(rest);
+
+                if is_negative {
+                    let position = normalized.offset_of(rest) + rest.len();
+                    return Err(ParseError::new(ParseErrorKind::ConflictingSigns, position));
+                }
+
+                s = rest;
+                is_negative = true;
+            }
+        }
+
+        if is_negative {
+            if s.starts_with(['-', '+']) {
+                let position = normalized.offset_of(s);
+                return Err(ParseError::new(ParseErrorKind::ConflictingSigns, position));
+            }
+
+            normalized.push_char('-', normalized.offset_of(s));
+        } else if s.starts_with(['-', '+']) {
+            normalized.push_str(&s[..1]);
+            s = &s[1..];
+        }
+
+        match self.symbol_position {
+            SymbolPosition::Prefix => {
+                if let Some(rest) = s.strip_prefix(self.symbol) {
+                    // the strict grammar only knows about `$`, so that's what stands in for it
+                    normalized.push_char('$', normalized.offset_of(s));
+                    s = rest;
+
+                    if self.allow_symbol_spacing {
+                        s = s.strip_prefix(is_space).unwrap_or(s);
+                    }
+                }
+            },
+
+            SymbolPosition::Suffix => {
+                if let Some(rest) = s.strip_suffix(self.symbol) {
+                    s = rest;
+
+                    if self.allow_symbol_spacing {
+                        s = s.strip_suffix(is_space).unwrap_or(s);
+                    }
+                }
+            },
+
+            SymbolPosition::Omitted => {},
+        }
+
+        let (integer, fraction) = match s.split_once(self.decimal_mark) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (s, None),
+        };
+        let is_separator = |c: char| match self.grouping_separator {
+            Some(separator) if is_space(separator) => is_space(c),
+            Some(separator) => c == separator,
+            None => false,
+        };
+
+        if integer.contains(is_separator) {
+            // the first group has one to three digits, and the rest have exactly three
+            let is_misplaced = |&(i, group): &(usize, &str)| match i {
+                0 => !(1..=3).contains(&group.len()),
+                _ => group.len() != 3,
+            };
+
+            if let Some((_, group)) = integer.split(is_separator).enumerate().find(is_misplaced) {
+                let position = normalized.offset_of(group);
+                return Err(ParseError::new(ParseErrorKind::InvalidGrouping, position));
+            }
+        }
+
+        let integer_start = normalized.offset_of(integer);
+
+        for (i, c) in integer.char_indices().filter(|&(_, c)| !is_separator(c)) {
+            self.push_digit_char(&mut normalized, c, integer_start + i)?;
+        }
+
+        if let Some(fraction) = fraction {
+            let fraction_start = normalized.offset_of(fraction);
+            normalized.push_char('.', fraction_start - self.decimal_mark.len_utf8());
+
+            for (i, c) in fraction.char_indices() {
+                self.push_digit_char(&mut normalized, c, fraction_start + i)?;
+            }
+
+            if self.allow_short_cents && fraction.len() == 1 {
+                normalized.push_char('0', fraction_start + 1);
+            }
+        }
+
+        Ok(normalized)
+    }
+
+    /// Pushes a character of the number itself, rewriting the decimal mark to `.` for the strict
+    /// grammar. A literal `.` or `$` is rejected when it isn't the decimal mark or the symbol, so
+    /// that the strict grammar can't mistake it for one.
+    fn push_digit_char(
+        &self,
+        normalized: &mut Normalized,
+        c: char,
+        offset: usize,
+    ) -> Result<(), ParseError> {
+        if c == self.decimal_mark {
+            normalized.push_char('.', offset);
+        } else if c == '.' || c == '$' {
+            return Err(ParseError::new(ParseErrorKind::InvalidDigit(c), offset));
+        } else {
+            normalized.push_char(c, offset);
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether or not `c` is a space, a non-breaking space, or a narrow non-breaking space, which
+/// are all used to separate digit groups in various locales.
+fn is_space(c: char) -> bool {
+    matches!(c, ' ' | '\u{a0}' | '\u{202f}')
+}
+
+/// A normalized version of some input string, which keeps track of where each of its bytes came
+/// from in the input so that errors can be reported in terms of the original input.
+struct Normalized<'a> {
+    input: &'a str,
+    text: String,
+    offsets: Vec<usize>,
+}
+
+impl<'a> Normalized<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            text: String::with_capacity(input.len() + 2),
+            offsets: Vec::with_capacity(input.len() + 3),
+        }
+    }
+
+    /// The byte offset of `s` in the input, which `s` must be a substring of.
+    fn offset_of(&self, s: &str) -> usize {
+        s.as_ptr() as usize - self.input.as_ptr() as usize
+    }
+
+    fn push_char(&mut self, c: char, offset: usize) {
+        self.text.push(c);
+        self.offsets
+            .extend(std::iter::repeat_n(offset, c.len_utf8()));
+    }
+
+    /// Pushes `s`, which must be a substring of the input.
+    fn push_str(&mut self, s: &str) {
+        let offset = self.offset_of(s);
+        self.text.push_str(s);
+        self.offsets.extend(offset..offset + s.len());
+    }
+
+    /// Parses the normalized text, mapping any error back to its position in the input.
+    fn parse(mut self) -> Result<Dollars, ParseError> {
+        // errors can point just past the end of the text
+        self.offsets.push(self.input.len());
+
+        self.text
+            .parse()
+            .map_err(|err: ParseError| ParseError::new(err.kind(), self.offsets[err.position()]))
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dollars {
+    /// Parses a literal for the [`dollars!`](crate::dollars) macro, panicking (and so failing
+    /// compilation) if it's invalid. String literals are stripped of their quotes first.
+    #[doc(hidden)]
+    pub const fn __from_literal(literal: &str) -> Self {
+        let literal = match literal.as_bytes() {
+            [b'"', inner @ .., b'"'] => match std::str::from_utf8(inner) {
+                Ok(inner) => inner,
+                Err(_) => panic!("invalid dollars literal: not valid UTF-8"),
+            },
+            _ => literal,
+        };
+
+        match parse_strict(literal) {
+            Ok(cent_value) => Self::from_cents(cent_value),
+            Err(err) => panic!("{}", err.kind.description()),
+        }
+    }
+
+    /// Parses a value from a string, accepting the non-standard inputs enabled in `options`.
+    ///
+    /// With the default options, this is exactly equivalent to the [`FromStr`](std::str::FromStr)
+    /// impl.
+    pub fn parse_with(s: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        if *options == ParseOptions::STRICT {
+            return s.parse();
+        }
+
+        options.normalize(s)?.parse()
+    }
+
+    /// Parses a value formatted according to the conventions of `locale`, like `1 234,56 $` for
+    /// [`Locale::FR_CA`].
+    ///
+    /// This is as strict as the [`FromStr`](std::str::FromStr) impl, except that it uses the
+    /// locale's decimal mark and currency symbol, and accepts the locale's digit grouping.
+    pub fn parse_locale(s: &str, locale: &Locale) -> Result<Self, ParseError> {
+        Self::parse_with(s, &locale.parse_options())
+    }
+}
+
+/// Unwraps a `Result` or returns its error early, since `?` can't be used in const fns.
+macro_rules! const_try {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        }
+    };
+}
+
+/// Parses the strict grammar into a number of cents.
+///
+/// This is a const fn so that the [`dollars!`](crate::dollars) macro can parse literals at
+/// compile time with the exact same grammar.
+pub(crate) const fn parse_strict(s: &str) -> Result<i64, ParseError> {
+    parse_decimal(s, "$", 2)
+}
+
+/// Parses the strict grammar, generalized to any currency symbol and any number of fractional
+/// digits, into a number of minor units.
+pub(crate) const fn parse_decimal(s: &str, symbol: &str, scale: u32) -> Result<i64, ParseError> {
+    match parse_bounded(s, symbol, scale, i64::MIN as i128, i64::MAX as i128) {
+        Ok(value) => Ok(value as i64),
+        Err(err) => Err(err),
+    }
+}
+
+/// Parses the same grammar as [`parse_decimal`], but into an `i128` that must lie between `min`
+/// and `max`, so that wider types can share the parser.
+pub(crate) const fn parse_bounded(
+    s: &str,
+    symbol: &str,
+    scale: u32,
+    min: i128,
+    max: i128,
+) -> Result<i128, ParseError> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+
+    let is_negative = match bytes.first() {
+        Some(b'-') => {
+            i += 1;
+            true
+        },
+
+        Some(b'+') => {
+            i += 1;
+            false
+        },
+
+        _ => false,
+    };
+
+    if starts_with_at(bytes, i, symbol.as_bytes()) {
+        i += symbol.len();
+    }
+
+    let mut j = i;
+
+    while j < bytes.len() {
+        if !bytes[j].is_ascii() {
+            return Err(ParseError::new(ParseErrorKind::NonAscii, j));
+        }
+
+        j += 1;
+    }
+
+    // the value is accumulated as a negative number, since the negative range is the larger one;
+    // that way, the minimum value parses without overflowing along the way
+    let digits_start = i;
+    let mut value = 0_i128;
+
+    while i < bytes.len() && bytes[i] != b'.' {
+        value = const_try!(push_digit(value, bytes, i, min));
+        i += 1;
+    }
+
+    if i == digits_start {
+        return Err(ParseError::new(ParseErrorKind::MissingDigits, i));
+    }
+
+    if i == bytes.len() {
+        value = match value.checked_mul(10_i128.pow(scale)) {
+            Some(value) if value >= min => value,
+            _ => return Err(ParseError::new(ParseErrorKind::Overflow, digits_start)),
+        };
+    } else {
+        let decimal_point = i;
+        i += 1;
+
+        if scale == 0 {
+            return Err(ParseError::new(
+                ParseErrorKind::BadCentsLength,
+                decimal_point,
+            ));
+        }
+
+        while i <= decimal_point + scale as usize {
+            match byte_at(bytes, i) {
+                None => {
+                    return Err(ParseError::new(
+                        ParseErrorKind::BadCentsLength,
+                        decimal_point,
+                    ))
+                },
+                Some(b'.') => return Err(ParseError::new(ParseErrorKind::ExtraDecimalPoint, i)),
+                Some(_) => value = const_try!(push_digit(value, bytes, i, min)),
+            }
+
+            i += 1;
+        }
+
+        match byte_at(bytes, i) {
+            None => {},
+            Some(b'.') => return Err(ParseError::new(ParseErrorKind::ExtraDecimalPoint, i)),
+            Some(b'0'..=b'9') => {
+                return Err(ParseError::new(
+                    ParseErrorKind::BadCentsLength,
+                    decimal_point,
+                ))
+            },
+            Some(_) => return Err(ParseError::new(ParseErrorKind::TrailingCharacters, i)),
+        }
+    }
+
+    if is_negative {
+        Ok(value)
+    } else {
+        match value.checked_neg() {
+            Some(value) if value <= max => Ok(value),
+            _ => Err(ParseError::new(ParseErrorKind::Overflow, digits_start)),
+        }
+    }
+}
+
+/// Whether or not `bytes[i..]` starts with `prefix`.
+const fn starts_with_at(bytes: &[u8], i: usize, prefix: &[u8]) -> bool {
+    if bytes.len() < i + prefix.len() {
+        return false;
+    }
+
+    let mut j = 0;
+
+    while j < prefix.len() {
+        if bytes[i + j] != prefix[j] {
+            return false;
+        }
+
+        j += 1;
+    }
+
+    true
+}
+
+const fn byte_at(bytes: &[u8], i: usize) -> Option<u8> {
+    if i < bytes.len() {
+        Some(bytes[i])
+    } else {
+        None
+    }
+}
+
+/// Shifts the digit at `bytes[i]` onto the end of the negative accumulator `value`, which must
+/// stay at or above `min`.
+const fn push_digit(value: i128, bytes: &[u8], i: usize, min: i128) -> Result<i128, ParseError> {
+    let digit = match bytes[i] {
+        b @ b'0'..=b'9' => (b - b'0') as i128,
+        b => return Err(ParseError::new(ParseErrorKind::InvalidDigit(b as char), i)),
+    };
+
+    match value.checked_mul(10) {
+        Some(value) => match value.checked_sub(digit) {
+            Some(value) if value >= min => Ok(value),
+            _ => Err(ParseError::new(ParseErrorKind::Overflow, i)),
+        },
+        None => Err(ParseError::new(ParseErrorKind::Overflow, i)),
+    }
+}
+
+/// Error capturing a failure to parse a [`Dollars`] from a string.
+///
+/// Along with the [kind](ParseError::kind) of failure, the error records the byte
+/// [position](ParseError::position) in the input where parsing went wrong, so that callers can
+/// point out exactly where the problem is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("failed to parse dollars: {kind} at byte {position}")]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    position: usize,
+}
+
+impl ParseError {
+    pub(crate) const fn new(kind: ParseErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    /// The kind of failure that occurred.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the input of the character that caused the failure.
+    ///
+    /// For failures that aren't caused by a single character, like overflow, this is the offset
+    /// of the start of the offending portion of the input.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl ParseErrorKind {
+    /// A static description of the failure, for the [`dollars!`](crate::dollars) macro to
+    /// report at compile time.
+    const fn description(&self) -> &'static str {
+        match self {
+            Self::InvalidDigit(_) => "invalid dollars literal: invalid digit",
+            Self::Overflow => "invalid dollars literal: value overflows",
+            Self::BadCentsLength => "invalid dollars literal: cents must be two digits long",
+            Self::ExtraDecimalPoint => "invalid dollars literal: too many decimal points",
+            Self::NonAscii => "invalid dollars literal: non-ASCII strings are not allowed",
+            Self::InvalidGrouping => "invalid dollars literal: misplaced digit grouping separators",
+            Self::ConflictingSigns => "invalid dollars literal: value has more than one sign",
+            Self::MissingDigits => "invalid dollars literal: missing digits",
+            Self::TrailingCharacters => "invalid dollars literal: unexpected trailing characters",
+            Self::UnknownWord => "invalid dollars literal: unknown word",
+            Self::MisplacedWord => "invalid dollars literal: misplaced word",
+            Self::CentsOutOfRange => "invalid dollars literal: cents out of range",
+        }
+    }
+}
+
+/// The kinds of failures that can occur when parsing a [`Dollars`] from a string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// A character that should have been a digit wasn't.
+    #[error("invalid digit '{0}'")]
+    InvalidDigit(char),
+
+    /// The value is too large or too small to represent.
+    #[error("value overflows")]
+    Overflow,
+
+    /// The cents portion isn't exactly two digits long (or, for other currencies, the minor
+    /// units portion doesn't have the right number of digits).
+    #[error("wrong number of digits after the decimal point")]
+    BadCentsLength,
+
+    /// There's more than one decimal point.
+    #[error("too many decimal points")]
+    ExtraDecimalPoint,
+
+    /// The input contains non-ASCII characters.
+    #[error("non-ASCII strings are not allowed")]
+    NonAscii,
+
+    /// Digit grouping separators are in the wrong places.
+    #[error("digit grouping separators are misplaced")]
+    InvalidGrouping,
+
+    /// The value is marked negative in more than one way, like `(-$1.00)`.
+    #[error("value has more than one sign")]
+    ConflictingSigns,
+
+    /// There are no digits where some were expected, like in `$.50`.
+    #[error("missing digits")]
+    MissingDigits,
+
+    /// There's unexpected input after an otherwise valid value.
+    #[error("unexpected trailing characters")]
+    TrailingCharacters,
+
+    /// A word isn't a number word, unit, or anything else that
+    /// [`parse_words`](Dollars::parse_words) understands.
+    #[error("unknown word")]
+    UnknownWord,
+
+    /// A word is known, but out of place, like `thousand` in `twenty thousand thousand`.
+    #[error("misplaced word")]
+    MisplacedWord,
+
+    /// The cents portion is 100 or more, like in `five dollars and 120 cents`.
+    #[error("cents out of range")]
+    CentsOutOfRange,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str, options: &ParseOptions) -> Option<i64> {
+        Dollars::parse_with(s, options).ok().map(|d| d.in_cents())
+    }
+
+    #[test]
+    fn strict_by_default() {
+        let options = ParseOptions::new();
+
+        assert_eq!(parse("$1.50", &options), Some(150));
+        assert_eq!(parse("$1,234.56", &options), None);
+        assert_eq!(parse(" 1.00 ", &options), None);
+        assert_eq!(parse("(4.00)", &options), None);
+        assert_eq!(parse("1.5", &options), None);
+    }
+
+    #[test]
+    fn individual_leniencies() {
+        let grouping = ParseOptions::new().grouping_separator(Some(','));
+        assert_eq!(parse("$1,234.56", &grouping), Some(123456));
+        assert_eq!(parse("-$1,234,567", &grouping), Some(-123456700));
+        assert_eq!(parse("999.99", &grouping), Some(99999));
+        assert_eq!(parse("1,23.45", &grouping), None);
+        assert_eq!(parse("1234,567", &grouping), None);
+        assert_eq!(parse(",123", &grouping), None);
+        assert_eq!(parse("1,234,", &grouping), None);
+
+        let whitespace = ParseOptions::new().allow_whitespace(true);
+        assert_eq!(parse(" 12.00\t", &whitespace), Some(1200));
+        assert_eq!(parse("1 2.00", &whitespace), None);
+
+        let parentheses = ParseOptions::new().allow_parentheses(true);
+        assert_eq!(parse("(45.00)", &parentheses), Some(-4500));
+        assert_eq!(parse("($4.00)", &parentheses), Some(-400));
+        assert_eq!(parse("(-4.00)", &parentheses), None);
+        assert_eq!(parse("(4.00", &parentheses), None);
+
+        let trailing_minus = ParseOptions::new().allow_trailing_minus(true);
+        assert_eq!(parse("$5.25-", &trailing_minus), Some(-525));
+        assert_eq!(parse("-5.25-", &trailing_minus), None);
+        assert_eq!(parse("5.25 -", &trailing_minus), Some(-525));
+        assert_eq!(parse("5.25  -", &trailing_minus), None);
+
+        let short_cents = ParseOptions::new().allow_short_cents(true);
+        assert_eq!(parse("1.5", &short_cents), Some(150));
+        assert_eq!(parse("1.x", &short_cents), None);
+
+        let currency_code = ParseOptions::new().allow_currency_code(true);
+        assert_eq!(parse("USD 1.00", &currency_code), Some(100));
+        assert_eq!(parse("1.00 USD", &currency_code), Some(100));
+        assert_eq!(parse("USD1.00", &currency_code), Some(100));
+        assert_eq!(parse("USD  \t5", &currency_code), None);
+        assert_eq!(parse("1.00\tUSD", &currency_code), None);
+        assert_eq!(parse("EUR 1.00", &currency_code), None);
+    }
+
+    #[test]
+    fn error_positions() {
+        let position = |s| {
+            Dollars::parse_with(s, &ParseOptions::LENIENT)
+                .unwrap_err()
+                .position()
+        };
+
+        assert_eq!(position("  ($1,2x4.5)"), 7);
+        assert_eq!(position("  1,23.45"), 4);
+        assert_eq!(position("(-1.00)"), 1);
+        assert_eq!(position("(1.00-)"), 5);
+        assert_eq!(position("USD 1.x"), 6);
+    }
+
+    #[test]
+    fn combined_leniencies() {
+        let options = ParseOptions::LENIENT;
+
+        assert_eq!(parse("  ($1,234.5)  ", &options), Some(-123450));
+        assert_eq!(parse("USD 1,000.00-", &options), Some(-100000));
+        assert_eq!(parse("(1.00-)", &options), None);
+        assert_eq!(parse("$ 12.00", &options), Some(1200));
+        assert_eq!(parse("5.00 -", &options), Some(-500));
+        assert_eq!(parse(" USD  \t5", &options), None);
+    }
+
+    #[test]
+    fn locale_conventions() {
+        let parse_locale = |s: &str, locale| Dollars::parse_locale(s, locale).map(|d| d.in_cents());
+        let error = |s: &str, locale| {
+            let err = Dollars::parse_locale(s, locale).unwrap_err();
+            (err.kind(), err.position())
+        };
+
+        assert_eq!(parse_locale("1.234,56\u{a0}$", &Locale::DE_DE), Ok(123456));
+        assert_eq!(parse_locale("-1234,56", &Locale::DE_DE), Ok(-123456));
+        assert_eq!(parse_locale("1 234,56 $", &Locale::FR_CA), Ok(123456));
+        assert_eq!(
+            parse_locale("-1\u{202f}234,56\u{a0}$", &Locale::FR_FR),
+            Ok(-123456)
+        );
+        assert_eq!(parse_locale("US$ 12,00", &Locale::PT_BR), Ok(1200));
+        assert_eq!(parse_locale("US$12,00", &Locale::PT_BR), Ok(1200));
+        assert_eq!(parse_locale("-$1,234.56", &Locale::EN_CA), Ok(-123456));
+
+        assert_eq!(
+            error("1.234,5 $", &Locale::DE_DE),
+            (ParseErrorKind::BadCentsLength, 5)
+        );
+        assert_eq!(
+            error("12.50", &Locale::FR_CA),
+            (ParseErrorKind::InvalidDigit('.'), 2)
+        );
+        assert_eq!(
+            error("$12,50", &Locale::FR_CA),
+            (ParseErrorKind::InvalidDigit('$'), 0)
+        );
+        assert_eq!(
+            error("1,2,3", &Locale::FR_CA),
+            (ParseErrorKind::ExtraDecimalPoint, 3)
+        );
+        assert_eq!(
+            error("12 34,00", &Locale::FR_CA),
+            (ParseErrorKind::InvalidGrouping, 3)
+        );
+        assert_eq!(
+            error("€12,00", &Locale::DE_DE),
+            (ParseErrorKind::NonAscii, 0)
+        );
+        assert_eq!(
+            error("99999999999999999,00 $", &Locale::FR_CA),
+            (ParseErrorKind::Overflow, 19)
+        );
+    }
+
+    #[test]
+    fn custom_symbol_and_decimal_mark() {
+        let options = ParseOptions::new()
+            .symbol("€")
+            .symbol_position(SymbolPosition::Suffix)
+            .decimal_mark(',');
+
+        assert_eq!(parse("12,50€", &options), Some(1250));
+        assert_eq!(parse("-12,50€", &options), Some(-1250));
+        assert_eq!(parse("12,50 €", &options), None);
+        assert_eq!(
+            parse("12,50 €", &options.allow_symbol_spacing(true)),
+            Some(1250)
+        );
+        assert_eq!(parse("€12,50", &options), None);
+        assert_eq!(parse("$12,50", &options), None);
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::{rounding, Currency, Money, RoundingMode};
+
+/// An exact decimal rate, like a tax rate, an interest rate, or a discount multiplier.
+///
+/// A rate is stored as an integer mantissa scaled by a power of ten, so `0.08875` is stored
+/// exactly as `8875 * 10^-5`. Rates can be parsed from either decimal (`"0.085"`) or percent
+/// (`"8.875%"`) strings.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rate {
+    mantissa: i64,
+    scale: u32,
+}
+
+impl Rate {
+    /// The largest supported scale, i.e. the maximum number of decimal places in a rate.
+    pub const MAX_SCALE: u32 = 18;
+    /// A rate of one, which leaves any amount unchanged.
+    pub const ONE: Self = Self {
+        mantissa: 1,
+        scale: 0,
+    };
+    /// A rate of zero.
+    pub const ZERO: Self = Self {
+        mantissa: 0,
+        scale: 0,
+    };
+
+    /// Constructs the rate `mantissa * 10^-scale`.
+    ///
+    /// Panics if `scale` is greater than [`MAX_SCALE`](Rate::MAX_SCALE).
+    pub fn new(mantissa: i64, scale: u32) -> Self {
+        assert!(
+            scale <= Self::MAX_SCALE,
+            "rate scale must be at most {}",
+            Self::MAX_SCALE
+        );
+
+        let (mut mantissa, mut scale) = (mantissa, scale);
+
+        // normalize so that equal rates compare equal regardless of trailing zeros
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+
+        Self { mantissa, scale }
+    }
+
+    /// The integer mantissa of the rate.
+    pub fn mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    /// The number of decimal places in the rate.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Multiplies `value` by the rate, rounding once at the end according to `mode`.
+    ///
+    /// Returns `None` if the result doesn't fit in an `i128`.
+    pub(crate) fn apply(&self, value: i128, mode: RoundingMode) -> Option<i128> {
+        value
+            .checked_mul(self.mantissa as i128)
+            .map(|scaled| rounding::div_round(scaled, 10_i128.pow(self.scale), mode))
+    }
+}
+
+impl Display for Rate {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let divisor = 10_u64.pow(self.scale);
+        let magnitude = self.mantissa.unsigned_abs();
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+
+        if self.scale == 0 {
+            write!(f, "{}{}", sign, magnitude)
+        } else {
+            write!(
+                f,
+                "{}{}.{:0width$}",
+                sign,
+                magnitude / divisor,
+                magnitude % divisor,
+                width = self.scale as usize,
+            )
+        }
+    }
+}
+
+impl FromStr for Rate {
+    type Err = ParseRateError;
+
+    fn from_str(s: &str) -> Result<Self, ParseRateError> {
+        // there may be a +/- in front for sign
+        // there may be a % at the end, which shifts the scale by two places
+        // the value may be an integer
+        // if there's a decimal point, it must be followed by at least one digit
+        let (s, is_percent) = match s.strip_suffix('%') {
+            Some(s) => (s, true),
+            None => (s, false),
+        };
+        let (s, is_negative) = match s.as_bytes().first() {
+            Some(b'-') => (&s[1..], true),
+            Some(b'+') => (&s[1..], false),
+            _ => (s, false),
+        };
+        let (integer, fraction) = match s.split_once('.') {
+            Some((_, "")) => return Err(ParseRateErrorKind::MissingDigits.into()),
+            Some(parts) => parts,
+            None => (s, ""),
+        };
+
+        if integer.is_empty() {
+            return Err(ParseRateErrorKind::MissingDigits.into());
+        }
+
+        let mantissa = integer
+            .chars()
+            .chain(fraction.chars())
+            .try_fold(0_i64, |acc, c| {
+                let digit = match c {
+                    '.' => return Err(ParseRateErrorKind::ExtraDecimalPoint),
+                    c => c.to_digit(10).ok_or(ParseRateErrorKind::InvalidDigit(c))?,
+                };
+
+                acc.checked_mul(10)
+                    .and_then(|acc| acc.checked_add(digit as i64))
+                    .ok_or(ParseRateErrorKind::Overflow)
+            })?;
+        let scale = fraction.len() as u32 + if is_percent { 2 } else { 0 };
+
+        if scale > Self::MAX_SCALE {
+            return Err(ParseRateErrorKind::TooPrecise.into());
+        }
+
+        Ok(Self::new(
+            if is_negative { -mantissa } else { mantissa },
+            scale,
+        ))
+    }
+}
+
+impl<C: Currency> Money<C> {
+    /// Multiplies the amount by `rate`, rounding the result to a whole number of minor units
+    /// according to `mode`.
+    ///
+    /// The multiplication is done with exact intermediate precision, so the result is only ever
+    /// rounded once. For example, `$19.99` at an `8.875%` tax rate is `$1.774...`, which is
+    /// `$1.77` with [`RoundingMode::HalfEven`].
+    ///
+    /// Panics if the result overflows; see [`checked_apply_rate`](Money::checked_apply_rate) for
+    /// a non-panicking version.
+    pub fn apply_rate(self, rate: Rate, mode: RoundingMode) -> Self {
+        self.checked_apply_rate(rate, mode)
+            .expect("overflow in Money::apply_rate")
+    }
+
+    /// Checked multiplication by a rate. Returns `None` if the result overflows.
+    pub fn checked_apply_rate(self, rate: Rate, mode: RoundingMode) -> Option<Self> {
+        rate.apply(self.minor_units() as i128, mode)
+            .and_then(|minor_units| i64::try_from(minor_units).ok())
+            .map(Self::from_minor_units)
+    }
+}
+
+/// Opaque error capturing a failure to parse a [`Rate`] from a string.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error("failed to parse rate: {0}")]
+pub struct ParseRateError(#[from] ParseRateErrorKind);
+
+#[derive(Clone, Debug, thiserror::Error)]
+enum ParseRateErrorKind {
+    #[error("invalid digit '{0}'")]
+    InvalidDigit(char),
+
+    #[error("missing digits")]
+    MissingDigits,
+
+    #[error("value overflows")]
+    Overflow,
+
+    #[error("too many decimal places")]
+    TooPrecise,
+
+    #[error("too many decimal points")]
+    ExtraDecimalPoint,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Dollars;
+
+    fn rate(s: &str) -> Rate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_decimal_and_percent() {
+        assert_eq!(rate("0.085"), Rate::new(85, 3));
+        assert_eq!(rate("8.875%"), Rate::new(8875, 5));
+        assert_eq!(rate("85%"), rate("0.85"));
+        assert_eq!(rate("1.50"), Rate::new(15, 1));
+        assert_eq!(rate("-0.1"), Rate::new(-1, 1));
+        assert_eq!(rate("+2"), Rate::new(2, 0));
+
+        for bad in [
+            "",
+            "%",
+            ".5",
+            "5.",
+            "1.2.3",
+            "1a",
+            "0.0000000000000000001",
+            "99999999999999999999",
+        ] {
+            assert!(
+                bad.parse::<Rate>().is_err(),
+                "{:?} should fail to parse",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(rate("8.875%").to_string(), "0.08875");
+        assert_eq!(rate("-1.5").to_string(), "-1.5");
+        assert_eq!(rate("300%").to_string(), "3");
+    }
+
+    #[test]
+    fn apply_rate() {
+        let price = Dollars::from(1999);
+
+        assert_eq!(
+            price.apply_rate(rate("8.875%"), RoundingMode::HalfEven),
+            Dollars::from(177)
+        );
+        assert_eq!(
+            price.apply_rate(rate("8.875%"), RoundingMode::Ceil),
+            Dollars::from(178)
+        );
+        assert_eq!(
+            price.apply_rate(rate("0.85"), RoundingMode::HalfEven),
+            Dollars::from(1699)
+        );
+        assert_eq!(
+            price.apply_rate(rate("0.85"), RoundingMode::Ceil),
+            Dollars::from(1700)
+        );
+        assert_eq!(
+            (-price).apply_rate(rate("0.85"), RoundingMode::Floor),
+            Dollars::from(-1700)
+        );
+        assert_eq!(price.apply_rate(Rate::ONE, RoundingMode::Truncate), price);
+        assert_eq!(
+            Dollars::from(i64::MAX).checked_apply_rate(rate("2"), RoundingMode::Truncate),
+            None
+        );
+    }
+}
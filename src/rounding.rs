@@ -0,0 +1,240 @@
+use std::cmp::Ordering;
+
+use crate::{Currency, Dollars, Money};
+
+/// A policy for rounding the result of an inexact operation, like division, to a whole number
+/// of cents.
+///
+/// The "half" modes round to the nearest cent, and only differ in how they break exact ties.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RoundingMode {
+    /// Round toward zero, discarding the remainder. This is what integer division does.
+    Truncate,
+
+    /// Round toward negative infinity.
+    Floor,
+
+    /// Round toward positive infinity.
+    Ceil,
+
+    /// Round to the nearest value, breaking ties toward positive infinity.
+    HalfUp,
+
+    /// Round to the nearest value, breaking ties toward the even neighbor (banker's rounding).
+    HalfEven,
+
+    /// Round to the nearest value, breaking ties away from zero.
+    HalfAwayFromZero,
+}
+
+/// Divides `numerator` by `denominator`, rounding the quotient according to `mode`.
+///
+/// Panics if `denominator` is zero, or if the division overflows (which only happens for
+/// `i128::MIN / -1`).
+pub(crate) fn div_round(numerator: i128, denominator: i128, mode: RoundingMode) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+
+    if remainder == 0 {
+        return quotient;
+    }
+
+    // the exact quotient lies strictly between `quotient` and `quotient + step`
+    let is_negative = (numerator < 0) != (denominator < 0);
+    let step = if is_negative { -1 } else { 1 };
+    let away = quotient + step;
+
+    match mode {
+        RoundingMode::Truncate => quotient,
+        RoundingMode::Floor => {
+            if is_negative {
+                away
+            } else {
+                quotient
+            }
+        },
+        RoundingMode::Ceil => {
+            if is_negative {
+                quotient
+            } else {
+                away
+            }
+        },
+        _ => match (remainder.unsigned_abs() * 2).cmp(&denominator.unsigned_abs()) {
+            Ordering::Less => quotient,
+            Ordering::Greater => away,
+            Ordering::Equal => match mode {
+                RoundingMode::HalfUp => {
+                    if is_negative {
+                        quotient
+                    } else {
+                        away
+                    }
+                },
+                RoundingMode::HalfEven => {
+                    if quotient % 2 == 0 {
+                        quotient
+                    } else {
+                        away
+                    }
+                },
+                _ => away,
+            },
+        },
+    }
+}
+
+impl<C: Currency> Money<C> {
+    /// Rounds the amount to a multiple of `increment` according to `mode`, like rounding to the
+    /// nearest nickel for cash payments.
+    ///
+    /// The sign of `increment` is ignored, and negative amounts round just like the modes say,
+    /// so `-$1.03` is `-$1.05` when rounded to a nickel with [`RoundingMode::Floor`]. To round to
+    /// a price point like `$x.99`, round to a dollar and then subtract a cent.
+    ///
+    /// Panics if `increment` is zero or the result overflows; see
+    /// [`checked_round_to`](Money::checked_round_to) for a non-panicking version.
+    pub fn round_to(self, increment: Self, mode: RoundingMode) -> Self {
+        self.checked_round_to(increment, mode)
+            .expect("zero increment or overflow in Money::round_to")
+    }
+
+    /// Checked rounding to a multiple of `increment`. Returns `None` if `increment` is zero or
+    /// the result overflows.
+    pub fn checked_round_to(self, increment: Self, mode: RoundingMode) -> Option<Self> {
+        let increment = increment.unsigned_abs() as i128;
+
+        if increment == 0 {
+            return None;
+        }
+
+        let multiple = div_round(self.minor_units() as i128, increment, mode);
+        i64::try_from(multiple * increment)
+            .ok()
+            .map(Self::from_minor_units)
+    }
+}
+
+impl Dollars {
+    /// Rounds the value toward zero to a whole number of dollars, so `-$1.50` becomes `-$1.00`.
+    pub fn trunc(self) -> Self {
+        self.round_to(Self::from(100), RoundingMode::Truncate)
+    }
+
+    /// Rounds the value down to a whole number of dollars, so `-$1.50` becomes `-$2.00`.
+    ///
+    /// Panics if the result overflows.
+    pub fn floor_dollars(self) -> Self {
+        self.round_to(Self::from(100), RoundingMode::Floor)
+    }
+
+    /// Rounds the value up to a whole number of dollars, so `$1.01` becomes `$2.00`.
+    ///
+    /// Panics if the result overflows.
+    pub fn ceil_dollars(self) -> Self {
+        self.round_to(Self::from(100), RoundingMode::Ceil)
+    }
+
+    /// Rounds the value to the nearest whole number of dollars, breaking ties away from zero, so
+    /// `-$1.50` becomes `-$2.00`.
+    ///
+    /// Panics if the result overflows.
+    pub fn round_dollars(self) -> Self {
+        self.round_to(Self::from(100), RoundingMode::HalfAwayFromZero)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_all(numerator: i128, denominator: i128) -> [i128; 6] {
+        [
+            RoundingMode::Truncate,
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::HalfUp,
+            RoundingMode::HalfEven,
+            RoundingMode::HalfAwayFromZero,
+        ]
+        .map(|mode| div_round(numerator, denominator, mode))
+    }
+
+    #[test]
+    fn exact_division_ignores_mode() {
+        assert_eq!(round_all(12, 4), [3; 6]);
+        assert_eq!(round_all(-12, 4), [-3; 6]);
+    }
+
+    #[test]
+    fn inexact_division() {
+        assert_eq!(round_all(10, 3), [3, 3, 4, 3, 3, 3]);
+        assert_eq!(round_all(-10, 3), [-3, -4, -3, -3, -3, -3]);
+        assert_eq!(round_all(11, 3), [3, 3, 4, 4, 4, 4]);
+        assert_eq!(round_all(11, -3), [-3, -4, -3, -4, -4, -4]);
+    }
+
+    #[test]
+    fn ties() {
+        assert_eq!(round_all(5, 2), [2, 2, 3, 3, 2, 3]);
+        assert_eq!(round_all(7, 2), [3, 3, 4, 4, 4, 4]);
+        assert_eq!(round_all(-5, 2), [-2, -3, -2, -2, -2, -3]);
+        assert_eq!(round_all(-7, 2), [-3, -4, -3, -3, -4, -4]);
+    }
+
+    #[test]
+    fn round_to_increments() {
+        let nickel = Dollars::from(5);
+
+        assert_eq!(
+            Dollars::from(103).round_to(nickel, RoundingMode::HalfEven),
+            Dollars::from(105)
+        );
+        assert_eq!(
+            Dollars::from(-103).round_to(nickel, RoundingMode::Floor),
+            Dollars::from(-105)
+        );
+        assert_eq!(
+            Dollars::from(-103).round_to(nickel, RoundingMode::Ceil),
+            Dollars::from(-100)
+        );
+        assert_eq!(
+            Dollars::from(-1025).round_to(-Dollars::from(50), RoundingMode::HalfUp),
+            Dollars::from(-1000)
+        );
+        assert_eq!(
+            Dollars::from(1234).round_to(Dollars::from(100), RoundingMode::Ceil) - Dollars::from(1),
+            Dollars::from(1299)
+        );
+        assert_eq!(
+            Dollars::from(103).checked_round_to(Dollars::ZERO, RoundingMode::HalfEven),
+            None
+        );
+        assert_eq!(
+            Dollars::MAX.checked_round_to(nickel, RoundingMode::Ceil),
+            None
+        );
+    }
+
+    #[test]
+    fn whole_dollars() {
+        let round_all = |cents: i64| {
+            let value = Dollars::from(cents);
+
+            [
+                value.trunc(),
+                value.floor_dollars(),
+                value.ceil_dollars(),
+                value.round_dollars(),
+            ]
+            .map(|value| value.in_cents())
+        };
+
+        assert_eq!(round_all(150), [100, 100, 200, 200]);
+        assert_eq!(round_all(-150), [-100, -200, -100, -200]);
+        assert_eq!(round_all(-149), [-100, -200, -100, -100]);
+        assert_eq!(round_all(-1), [0, -100, 0, 0]);
+        assert_eq!(round_all(300), [300; 4]);
+        assert_eq!(Dollars::MIN.trunc(), Dollars::from(i64::MIN / 100 * 100));
+    }
+}
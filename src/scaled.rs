@@ -0,0 +1,240 @@
+use std::str::FromStr;
+
+use crate::format::{Amount, Formatted};
+use crate::macros::impl_amount;
+use crate::{parse, rounding, Dollars, FormatOptions, ParseError, RoundingMode};
+
+/// A dollar value with a fixed number of decimal places, backed by a single integer value in
+/// units of 10<sup>-`DECIMALS`</sup> dollars.
+///
+/// This is for amounts that need more precision than whole cents, like unit prices (fuel at
+/// `$3.459` a gallon) or interest accruals. Arithmetic is exact, and values are only rounded
+/// when settling into [`Dollars`] with [`round_to_cents`](ScaledDollars::round_to_cents):
+///
+/// ```
+/// # use dollars::{Dollars, Mills, RoundingMode};
+/// let price: Mills = "$3.459".parse().unwrap();
+/// let total = price * 12;
+///
+/// assert_eq!(total.to_string(), "$41.508");
+/// assert_eq!(total.round_to_cents(RoundingMode::HalfEven), Dollars::from(4151));
+/// ```
+///
+/// `DECIMALS` can be at most 18; larger values fail to compile:
+///
+/// ```compile_fail
+/// # use dollars::ScaledDollars;
+/// let value = ScaledDollars::<19>::default();
+/// ```
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ScaledDollars<const DECIMALS: u8> {
+    units: i64,
+}
+
+/// Dollars with three decimal places, i.e. in mills.
+pub type Mills = ScaledDollars<3>;
+
+/// Dollars with six decimal places, i.e. in micro-dollars.
+pub type Micros = ScaledDollars<6>;
+
+impl<const DECIMALS: u8> ScaledDollars<DECIMALS> {
+    /// The largest representable value.
+    pub const MAX: Self = Self::from_units(i64::MAX);
+    /// The smallest representable value.
+    pub const MIN: Self = Self::from_units(i64::MIN);
+    /// The number of units in a dollar.
+    const UNITS_PER_DOLLAR: i64 = {
+        assert!(
+            DECIMALS <= 18,
+            "ScaledDollars supports at most 18 decimal places"
+        );
+        10_i64.pow(DECIMALS as u32)
+    };
+    /// Zero dollars.
+    pub const ZERO: Self = Self::from_units(0);
+
+    /// Constructs a value from a number of units of 10<sup>-`DECIMALS`</sup> dollars.
+    pub const fn from_units(units: i64) -> Self {
+        let _ = Self::UNITS_PER_DOLLAR;
+        Self { units }
+    }
+
+    /// Converts a value in whole cents exactly. Returns `None` if the result overflows, or if
+    /// `DECIMALS` is less than two and the value has cents that can't be represented.
+    pub fn checked_from_dollars(value: Dollars) -> Option<Self> {
+        let units = rounding::div_round(
+            value.in_cents() as i128 * Self::UNITS_PER_DOLLAR as i128,
+            100,
+            RoundingMode::Truncate,
+        );
+
+        if units * 100 != value.in_cents() as i128 * Self::UNITS_PER_DOLLAR as i128 {
+            return None;
+        }
+
+        i64::try_from(units).ok().map(Self::from_units)
+    }
+
+    /// The value in units of 10<sup>-`DECIMALS`</sup> dollars.
+    pub const fn units(&self) -> i64 {
+        self.units
+    }
+
+    /// The dollars portion of the value.
+    pub const fn dollars(&self) -> i64 {
+        (self.units / Self::UNITS_PER_DOLLAR).abs()
+    }
+
+    /// The fractional portion of the value, in units of 10<sup>-`DECIMALS`</sup> dollars.
+    pub const fn fraction(&self) -> i64 {
+        (self.units % Self::UNITS_PER_DOLLAR).abs()
+    }
+
+    /// Rounds the value to a whole number of cents according to `mode`.
+    ///
+    /// Panics if the result overflows, which is only possible with fewer than two decimal
+    /// places; see [`checked_round_to_cents`](ScaledDollars::checked_round_to_cents) for a
+    /// non-panicking version.
+    pub fn round_to_cents(self, mode: RoundingMode) -> Dollars {
+        self.checked_round_to_cents(mode)
+            .expect("overflow in ScaledDollars::round_to_cents")
+    }
+
+    /// Checked rounding to a whole number of cents. Returns `None` if the result overflows.
+    pub fn checked_round_to_cents(self, mode: RoundingMode) -> Option<Dollars> {
+        let cent_value = rounding::div_round(
+            self.units as i128 * 100,
+            Self::UNITS_PER_DOLLAR as i128,
+            mode,
+        );
+
+        i64::try_from(cent_value).ok().map(Dollars::from)
+    }
+
+    /// Formats the value according to `options`.
+    pub fn format_with(self, options: &FormatOptions) -> Formatted<'_> {
+        Formatted::new(Amount::new(self.units.into(), DECIMALS.into()), options)
+    }
+}
+
+impl_amount! {
+    impl[const DECIMALS: u8] ScaledDollars<DECIMALS> {
+        repr: (i64, u64),
+        from: from_units,
+        get: units,
+        /// Formats the value with all of its decimal places, like `-$3.459`, respecting the same
+        /// flags as [`Dollars`].
+        display: FormatOptions::DEFAULT,
+    }
+}
+
+impl<const DECIMALS: u8> Default for ScaledDollars<DECIMALS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+/// Parses a value with the same grammar as [`Dollars`], except with exactly `DECIMALS` digits
+/// after the decimal point, like `$0.0004` for `ScaledDollars<4>`.
+impl<const DECIMALS: u8> FromStr for ScaledDollars<DECIMALS> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        parse::parse_decimal(s, "$", DECIMALS.into()).map(Self::from_units)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseErrorKind;
+
+    #[test]
+    fn printing_and_parsing() {
+        assert_eq!(Mills::from_units(3459).to_string(), "$3.459");
+        assert_eq!(ScaledDollars::<4>::from_units(-4).to_string(), "-$0.0004");
+        assert_eq!(
+            format!("{:#}", Micros::from_units(1_234_567_890_123)),
+            "$1,234,567.890123"
+        );
+        assert_eq!(ScaledDollars::<0>::from_units(-12).to_string(), "-$12");
+
+        assert_eq!("$0.0004".parse(), Ok(ScaledDollars::<4>::from_units(4)));
+        assert_eq!("-3.459".parse(), Ok(Mills::from_units(-3459)));
+        assert_eq!("12".parse(), Ok(Micros::from_units(12_000_000)));
+        assert_eq!(
+            "$3.45".parse::<Mills>().unwrap_err().kind(),
+            ParseErrorKind::BadCentsLength
+        );
+
+        for units in [0, 1, -1, i64::MAX, i64::MIN] {
+            let value = Micros::from_units(units);
+            assert_eq!(value.to_string().parse(), Ok(value));
+        }
+    }
+
+    #[test]
+    fn rounding_to_cents() {
+        let accrual = Micros::from_units(-1_234_567);
+
+        assert_eq!(
+            accrual.round_to_cents(RoundingMode::HalfEven),
+            Dollars::from(-123)
+        );
+        assert_eq!(
+            accrual.round_to_cents(RoundingMode::Floor),
+            Dollars::from(-124)
+        );
+        assert_eq!(
+            accrual.round_to_cents(RoundingMode::Truncate),
+            Dollars::from(-123)
+        );
+        assert_eq!(
+            Mills::from_units(5).round_to_cents(RoundingMode::HalfEven),
+            Dollars::ZERO
+        );
+        assert_eq!(
+            Mills::from_units(5).round_to_cents(RoundingMode::HalfUp),
+            Dollars::from(1)
+        );
+        assert_eq!(
+            ScaledDollars::<0>::MAX.checked_round_to_cents(RoundingMode::HalfEven),
+            None
+        );
+    }
+
+    #[test]
+    fn from_dollars() {
+        let value = Dollars::from(-1234);
+
+        assert_eq!(
+            Micros::checked_from_dollars(value),
+            Some(Micros::from_units(-12_340_000))
+        );
+        assert_eq!(
+            Micros::checked_from_dollars(value)
+                .unwrap()
+                .round_to_cents(RoundingMode::Truncate),
+            value
+        );
+        assert_eq!(ScaledDollars::<1>::checked_from_dollars(value), None);
+        assert_eq!(
+            ScaledDollars::<1>::checked_from_dollars(Dollars::from(120)),
+            Some(ScaledDollars::from_units(12))
+        );
+        assert_eq!(Micros::checked_from_dollars(Dollars::MAX), None);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let price = ScaledDollars::<4>::from_units(4);
+
+        assert_eq!(price * 2500, ScaledDollars::from_units(10_000));
+        assert_eq!(
+            [price, price, -price].iter().sum::<ScaledDollars<4>>(),
+            price
+        );
+        assert_eq!((price - price * 3).fraction(), 8);
+        assert_eq!(Mills::MAX.checked_add(Mills::from_units(1)), None);
+    }
+}
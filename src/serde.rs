@@ -0,0 +1,162 @@
+//! [Serde](https://serde.rs) support for [`Dollars`], enabled by the `serde` feature.
+//!
+//! By default, [`Dollars`] (de)serializes as its [`Display`](std::fmt::Display) string, like
+//! `"$12.34"`. The modules below provide alternative representations, which can be selected
+//! with `#[serde(with = "...")]`:
+//!
+//! ```
+//! use dollars::Dollars;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct LineItem {
+//!     #[serde(with = "dollars::serde::cents")]
+//!     price: Dollars,
+//!
+//!     #[serde(with = "dollars::serde::decimal")]
+//!     tax: Dollars,
+//! }
+//!
+//! let item = LineItem {
+//!     price: Dollars::from(1234),
+//!     tax: Dollars::from(123),
+//! };
+//! let json = r#"{"price":1234,"tax":"1.23"}"#;
+//!
+//! assert_eq!(serde_json::to_string(&item).unwrap(), json);
+//! assert_eq!(serde_json::from_str::<LineItem>(json).unwrap(), item);
+//! ```
+//!
+//! All of the string representations deserialize using [`Dollars`]'s [`FromStr`] impl, so they
+//! accept anything it does and report its [`ParseError`](crate::ParseError)s.
+
+use std::fmt::{self, Formatter};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::{Dollars, FormatOptions, SymbolPosition};
+
+impl Serialize for Dollars {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        display::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Dollars {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        display::deserialize(deserializer)
+    }
+}
+
+/// (De)serializes [`Dollars`] as its [`Display`](std::fmt::Display) string, like `"$12.34"`.
+///
+/// This is the default representation.
+pub mod display {
+    use super::*;
+
+    /// Serializes the value as its [`Display`](std::fmt::Display) string.
+    pub fn serialize<S: Serializer>(value: &Dollars, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes the value from a string using [`Dollars`]'s [`FromStr`] impl.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Dollars, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::<Dollars>(PhantomData))
+    }
+}
+
+/// (De)serializes [`Dollars`] as an integer number of cents, like `1234`.
+pub mod cents {
+    use super::*;
+
+    /// Serializes the value as its [`in_cents`](Dollars::in_cents) value.
+    pub fn serialize<S: Serializer>(value: &Dollars, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(value.in_cents())
+    }
+
+    /// Deserializes the value from an integer number of cents.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Dollars, D::Error> {
+        i64::deserialize(deserializer).map(Dollars::from)
+    }
+}
+
+/// (De)serializes [`Dollars`] as a decimal string without the dollar sign, like `"12.34"`.
+pub mod decimal {
+    use super::*;
+
+    const OPTIONS: FormatOptions = FormatOptions::new().symbol_position(SymbolPosition::Omitted);
+
+    /// Serializes the value as a decimal string without the dollar sign.
+    pub fn serialize<S: Serializer>(value: &Dollars, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&value.format_with(&OPTIONS))
+    }
+
+    /// Deserializes the value from a string using [`Dollars`]'s [`FromStr`] impl.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Dollars, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::<Dollars>(PhantomData))
+    }
+}
+
+struct FromStrVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a dollar amount string")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        s.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct LineItem {
+        price: Dollars,
+
+        #[serde(with = "crate::serde::cents")]
+        cost: Dollars,
+
+        #[serde(with = "crate::serde::decimal")]
+        tax: Dollars,
+    }
+
+    #[test]
+    fn round_trip() {
+        let item = LineItem {
+            price: Dollars::from(999),
+            cost: Dollars::from(-567),
+            tax: Dollars::from(-5),
+        };
+        let json = serde_json::to_string(&item).unwrap();
+
+        assert_eq!(json, r#"{"price":"$9.99","cost":-567,"tax":"-0.05"}"#);
+        assert_eq!(serde_json::from_str::<LineItem>(&json).unwrap(), item);
+        assert_eq!(
+            serde_json::from_str::<Dollars>(r#""-$1234.56""#).unwrap(),
+            Dollars::from(-123456)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = serde_json::from_str::<Dollars>(r#""$12.3""#).unwrap_err();
+        assert!(err.to_string().contains("failed to parse dollars"));
+
+        assert!(serde_json::from_str::<Dollars>("1234").is_err());
+    }
+}
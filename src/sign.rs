@@ -0,0 +1,115 @@
+use crate::Dollars;
+
+/// The sign of a value, with zero as its own case rather than lumped in with either side.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Sign {
+    /// Less than zero.
+    Negative,
+
+    /// Exactly zero.
+    Zero,
+
+    /// Greater than zero.
+    Positive,
+}
+
+impl Dollars {
+    /// The sign of the value.
+    pub const fn sign(&self) -> Sign {
+        match self.in_cents() {
+            ..=-1 => Sign::Negative,
+            0 => Sign::Zero,
+            1.. => Sign::Positive,
+        }
+    }
+
+    /// Splits the value into its sign, dollars, and cents, so that `-$1.50` becomes
+    /// `(Sign::Negative, 1, 50)`.
+    ///
+    /// Unlike [`dollars`](Dollars::dollars) and [`cents`](Dollars::cents), this keeps the sign,
+    /// and works for every value including [`Dollars::MIN`]. The parts can be put back together
+    /// with [`from_parts`](Dollars::from_parts).
+    pub const fn to_parts(&self) -> (Sign, u64, u8) {
+        let magnitude = self.unsigned_abs();
+        (self.sign(), magnitude / 100, (magnitude % 100) as u8)
+    }
+
+    /// Builds a value from its sign, dollars, and cents, the inverse of
+    /// [`to_parts`](Dollars::to_parts).
+    ///
+    /// Returns `None` if `cents` is 100 or more, if the value overflows, or if the sign doesn't
+    /// match the magnitude: [`Sign::Zero`] requires zero dollars and cents, and the other signs
+    /// require a nonzero amount.
+    pub const fn from_parts(sign: Sign, dollars: u64, cents: u8) -> Option<Self> {
+        if cents >= 100 {
+            return None;
+        }
+
+        let magnitude = match dollars.checked_mul(100) {
+            Some(magnitude) => match magnitude.checked_add(cents as u64) {
+                Some(magnitude) => magnitude,
+                None => return None,
+            },
+            None => return None,
+        };
+
+        match (sign, magnitude) {
+            (Sign::Zero, 0) => Some(Self::ZERO),
+            (Sign::Zero, _) | (_, 0) => None,
+            (Sign::Positive, _) if magnitude <= i64::MAX as u64 => {
+                Some(Self::from_cents(magnitude as i64))
+            },
+            (Sign::Negative, _) if magnitude <= i64::MIN.unsigned_abs() => {
+                Some(Self::from_cents((magnitude as i64).wrapping_neg()))
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts() {
+        assert_eq!(Dollars::from(-150).to_parts(), (Sign::Negative, 1, 50));
+        assert_eq!(Dollars::from(5).to_parts(), (Sign::Positive, 0, 5));
+        assert_eq!(Dollars::ZERO.to_parts(), (Sign::Zero, 0, 0));
+        assert_eq!(
+            Dollars::MIN.to_parts(),
+            (Sign::Negative, 92233720368547758, 8)
+        );
+
+        for cents in [0, 1, -1, 150, -150, i64::MAX, i64::MIN] {
+            let value = Dollars::from(cents);
+            let (sign, dollars, cents) = value.to_parts();
+
+            assert_eq!(Dollars::from_parts(sign, dollars, cents), Some(value));
+        }
+    }
+
+    #[test]
+    fn invalid_parts() {
+        assert_eq!(Dollars::from_parts(Sign::Positive, 1, 100), None);
+        assert_eq!(Dollars::from_parts(Sign::Zero, 0, 1), None);
+        assert_eq!(Dollars::from_parts(Sign::Negative, 0, 0), None);
+        assert_eq!(
+            Dollars::from_parts(Sign::Positive, 92233720368547758, 8),
+            None
+        );
+        assert_eq!(
+            Dollars::from_parts(Sign::Negative, 92233720368547758, 9),
+            None
+        );
+        assert_eq!(Dollars::from_parts(Sign::Positive, u64::MAX, 0), None);
+    }
+
+    #[test]
+    fn signs() {
+        assert_eq!(Dollars::from(-1).sign(), Sign::Negative);
+        assert_eq!(Dollars::ZERO.sign(), Sign::Zero);
+        assert_eq!(Dollars::MAX.sign(), Sign::Positive);
+        assert!(Sign::Negative < Sign::Zero && Sign::Zero < Sign::Positive);
+    }
+}
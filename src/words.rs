@@ -0,0 +1,670 @@
+use crate::{Dollars, ParseError, ParseErrorKind, Sign};
+
+/// A style for spelling out a value in words with [`to_words`](Dollars::to_words).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum WordsStyle {
+    /// The style used on checks, with cents as a fraction, like
+    /// `One thousand two hundred thirty-four and 56/100 dollars`.
+    Check,
+
+    /// The style used in speech, like
+    /// `one thousand two hundred thirty-four dollars and fifty-six cents`.
+    Spoken,
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// The names of each power of a thousand, enough for the whole range of [`Dollars`].
+const SCALES: [&str; 6] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+];
+
+impl Dollars {
+    /// Spells out the value in words, in the given `style`.
+    ///
+    /// Negative values are prefixed with "minus", and singular units are used where they
+    /// apply, so `$1.01` is `one dollar and one cent` in the spoken style. In the check style,
+    /// the first letter is capitalized, and the unit is always `dollars`, as it's printed on
+    /// checks:
+    ///
+    /// ```
+    /// # use dollars::{Dollars, WordsStyle};
+    /// let value = Dollars::from(123456);
+    ///
+    /// assert_eq!(
+    ///     value.to_words(WordsStyle::Check),
+    ///     "One thousand two hundred thirty-four and 56/100 dollars"
+    /// );
+    /// assert_eq!(
+    ///     value.to_words(WordsStyle::Spoken),
+    ///     "one thousand two hundred thirty-four dollars and fifty-six cents"
+    /// );
+    /// ```
+    pub fn to_words(self, style: WordsStyle) -> String {
+        let (sign, dollars, cents) = self.to_parts();
+        let mut words = String::new();
+
+        if sign == Sign::Negative {
+            words.push_str("minus ");
+        }
+
+        match style {
+            WordsStyle::Check => {
+                push_number(&mut words, dollars);
+                words.push_str(&format!(" and {:02}/100 dollars", cents));
+                words[..1].make_ascii_uppercase();
+            },
+
+            WordsStyle::Spoken => {
+                if dollars > 0 || cents == 0 {
+                    push_number(&mut words, dollars);
+                    words.push_str(if dollars == 1 { " dollar" } else { " dollars" });
+                }
+
+                if cents > 0 {
+                    if dollars > 0 {
+                        words.push_str(" and ");
+                    }
+
+                    push_number(&mut words, cents.into());
+                    words.push_str(if cents == 1 { " cent" } else { " cents" });
+                }
+            },
+        }
+
+        words
+    }
+
+    /// Parses a value spelled out in English words, like `twelve dollars and fifty cents`.
+    ///
+    /// This accepts both styles produced by [`to_words`](Dollars::to_words), along with some
+    /// common variations:
+    ///
+    /// * Digits in place of number words, like `12 dollars and 50 cents` or `1,000 dollars`
+    /// * Check-style cents after dollars, like `twelve dollars and 56/100`
+    /// * `and` within numbers, like `one hundred and five dollars`
+    /// * Multiples of a hundred, like `twelve hundred dollars`
+    /// * `a` for one, `buck` for dollar, and `grand` for a thousand dollars
+    /// * Cents without a unit after dollars, like `a buck fifty`
+    /// * A leading `minus` or `negative` for negative values
+    ///
+    /// Case is ignored, and words can be separated by whitespace, hyphens, or commas. Error
+    /// positions point at the start of the offending word.
+    ///
+    /// ```
+    /// # use dollars::Dollars;
+    /// assert_eq!(Dollars::parse_words("a buck fifty"), Ok(Dollars::from(150)));
+    /// assert_eq!(
+    ///     Dollars::parse_words("One thousand two hundred and 56/100 dollars"),
+    ///     Ok(Dollars::from(120056))
+    /// );
+    /// ```
+    pub fn parse_words(s: &str) -> Result<Self, ParseError> {
+        WordsParser::new(s)?.parse()
+    }
+}
+
+/// Pushes the words for `n`, like `one thousand two hundred thirty-four`.
+fn push_number(words: &mut String, n: u64) {
+    if n == 0 {
+        words.push_str(ONES[0]);
+        return;
+    }
+
+    let mut groups = Vec::with_capacity(SCALES.len());
+    let mut rest = n;
+
+    while rest > 0 {
+        groups.push((rest % 1000) as usize);
+        rest /= 1000;
+    }
+
+    let mut is_first = true;
+
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+
+        if !is_first {
+            words.push(' ');
+        }
+
+        push_group(words, group);
+
+        if scale > 0 {
+            words.push(' ');
+            words.push_str(SCALES[scale]);
+        }
+
+        is_first = false;
+    }
+}
+
+/// Pushes the words for a group of three digits, which must be between 1 and 999.
+fn push_group(words: &mut String, group: usize) {
+    let (hundreds, rest) = (group / 100, group % 100);
+
+    if hundreds > 0 {
+        words.push_str(ONES[hundreds]);
+        words.push_str(" hundred");
+
+        if rest > 0 {
+            words.push(' ');
+        }
+    }
+
+    match rest {
+        0 => {},
+        1..=19 => words.push_str(ONES[rest]),
+        _ => {
+            words.push_str(TENS[rest / 10]);
+
+            if rest % 10 > 0 {
+                words.push('-');
+                words.push_str(ONES[rest % 10]);
+            }
+        },
+    }
+}
+
+/// A word of the input to [`parse_words`](Dollars::parse_words), classified by meaning.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Word {
+    /// A number word below a hundred, like `seven` or `forty`.
+    Number(u64),
+    /// A number written in digits, like `12`.
+    Digits(u64),
+    Hundred,
+    /// A power of a thousand, like `million`.
+    Scale(u64),
+    /// `a`, meaning one.
+    A,
+    And,
+    Minus,
+    Dollars,
+    Cents,
+    /// `grand`, meaning a thousand dollars.
+    Grand,
+    /// A check-style fraction of a dollar, like `56/100`.
+    Fraction(u64),
+}
+
+impl Word {
+    fn classify(text: &str, position: usize) -> Result<Self, ParseError> {
+        let is = |word: &str| text.eq_ignore_ascii_case(word);
+        let error = |kind| ParseError::new(kind, position);
+
+        if let Some(n) = ONES.iter().position(|word| is(word)) {
+            return Ok(Self::Number(n as u64));
+        }
+
+        if let Some(n) = TENS.iter().skip(2).position(|word| is(word)) {
+            return Ok(Self::Number((n as u64 + 2) * 10));
+        }
+
+        if let Some(scale) = SCALES.iter().skip(1).position(|word| is(word)) {
+            return Ok(Self::Scale(1000_u64.pow(scale as u32 + 1)));
+        }
+
+        if text.bytes().all(|b| b.is_ascii_digit() || b == b',') {
+            // commas only make it into a word between digits, where they group thousands
+            let mut groups = text.split(',');
+            let is_grouped = groups.next().is_some_and(|group| group.len() <= 3)
+                && groups.all(|group| group.len() == 3);
+
+            if text.contains(',') && !is_grouped {
+                return Err(error(ParseErrorKind::InvalidGrouping));
+            }
+
+            return text
+                .replace(',', "")
+                .parse()
+                .map(Self::Digits)
+                .map_err(|_| error(ParseErrorKind::Overflow));
+        }
+
+        if let Some(cents) = text.strip_suffix("/100") {
+            if cents.len() != 2 || !cents.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(error(ParseErrorKind::BadCentsLength));
+            }
+
+            return Ok(Self::Fraction(cents.parse().unwrap()));
+        }
+
+        let word = match () {
+            _ if is("hundred") => Self::Hundred,
+            _ if is("a") || is("an") => Self::A,
+            _ if is("and") => Self::And,
+            _ if is("minus") || is("negative") => Self::Minus,
+            _ if is("dollar") || is("dollars") || is("buck") || is("bucks") => Self::Dollars,
+            _ if is("cent") || is("cents") => Self::Cents,
+            _ if is("grand") => Self::Grand,
+            _ => return Err(error(ParseErrorKind::UnknownWord)),
+        };
+
+        Ok(word)
+    }
+}
+
+/// A recursive descent parser for [`parse_words`](Dollars::parse_words), over the classified
+/// words of the input.
+struct WordsParser {
+    words: Vec<(Word, usize)>,
+    len: usize,
+    i: usize,
+}
+
+impl WordsParser {
+    fn new(s: &str) -> Result<Self, ParseError> {
+        let mut words = Vec::new();
+        let mut start = None;
+
+        // hyphens only separate words, like in `thirty-four`, so that `-5` isn't silently
+        // taken as positive, and commas between digits group them, like in `1,000`
+        let bytes = s.as_bytes();
+        let is_separator = |i: usize, c: char| match c {
+            '-' => {
+                i > 0
+                    && bytes[i - 1].is_ascii_alphabetic()
+                    && bytes.get(i + 1).is_some_and(u8::is_ascii_alphabetic)
+            },
+            ',' => {
+                !(i > 0
+                    && bytes[i - 1].is_ascii_digit()
+                    && bytes.get(i + 1).is_some_and(u8::is_ascii_digit))
+            },
+            c => c.is_whitespace(),
+        };
+
+        for (i, c) in s.char_indices().chain([(s.len(), ' ')]) {
+            match (start, is_separator(i, c)) {
+                (None, false) => start = Some(i),
+                (Some(j), true) => {
+                    words.push((Word::classify(&s[j..i], j)?, j));
+                    start = None;
+                },
+                _ => {},
+            }
+        }
+
+        Ok(Self {
+            words,
+            len: s.len(),
+            i: 0,
+        })
+    }
+
+    fn peek(&self) -> Option<Word> {
+        self.words.get(self.i).map(|&(word, _)| word)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<Word> {
+        self.words.get(self.i + offset).map(|&(word, _)| word)
+    }
+
+    /// The position of the current word, or the end of the input if there are none left.
+    fn position(&self) -> usize {
+        self.words
+            .get(self.i)
+            .map_or(self.len, |&(_, position)| position)
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(kind, self.position())
+    }
+
+    fn eat(&mut self, word: Word) -> bool {
+        let is_next = self.peek() == Some(word);
+        self.i += is_next as usize;
+        is_next
+    }
+
+    fn parse(mut self) -> Result<Dollars, ParseError> {
+        let is_negative = self.eat(Word::Minus);
+        let start = self.position();
+
+        let Some(amount) = self.parse_number()? else {
+            return Err(match self.peek() {
+                Some(_) => self.error(ParseErrorKind::MisplacedWord),
+                None => self.error(ParseErrorKind::MissingDigits),
+            });
+        };
+
+        let (dollars, cents) = match self.peek() {
+            Some(Word::Dollars | Word::Grand) => {
+                let dollars = match self.peek() {
+                    Some(Word::Grand) => amount.checked_mul(1000),
+                    _ => Some(amount),
+                };
+                self.i += 1;
+
+                let has_and = self.eat(Word::And);
+                let cents_start = self.position();
+
+                let cents = match self.peek() {
+                    Some(Word::Fraction(cents)) => {
+                        self.i += 1;
+                        Some(cents)
+                    },
+                    _ => self.parse_number()?,
+                };
+
+                let cents = match cents {
+                    Some(cents) => {
+                        self.eat(Word::Cents);
+                        cents
+                    },
+                    None if has_and => {
+                        return Err(match self.peek() {
+                            Some(_) => self.error(ParseErrorKind::MisplacedWord),
+                            None => self.error(ParseErrorKind::MissingDigits),
+                        })
+                    },
+                    None => 0,
+                };
+
+                if cents >= 100 {
+                    return Err(ParseError::new(
+                        ParseErrorKind::CentsOutOfRange,
+                        cents_start,
+                    ));
+                }
+
+                (dollars, cents)
+            },
+
+            Some(Word::Cents) => {
+                self.i += 1;
+                (Some(0), amount)
+            },
+
+            Some(Word::And | Word::Fraction(_)) => {
+                self.eat(Word::And);
+
+                let Some(Word::Fraction(cents)) = self.peek() else {
+                    return Err(self.error(ParseErrorKind::MisplacedWord));
+                };
+                self.i += 1;
+                self.eat(Word::Dollars);
+
+                (Some(amount), cents)
+            },
+
+            _ => (Some(amount), 0),
+        };
+
+        if self.i < self.words.len() {
+            return Err(self.error(ParseErrorKind::TrailingCharacters));
+        }
+
+        let cent_value = dollars
+            .and_then(|dollars| (dollars as i128 * 100).checked_add(cents as i128))
+            .map(|magnitude| if is_negative { -magnitude } else { magnitude })
+            .and_then(|cent_value| i64::try_from(cent_value).ok())
+            .ok_or(ParseError::new(ParseErrorKind::Overflow, start))?;
+
+        Ok(Dollars::from(cent_value))
+    }
+
+    /// Parses a number made up of number words, digits, and scales, like `twelve hundred` or
+    /// `two million five hundred thousand`, or returns `None` if there isn't one.
+    fn parse_number(&mut self) -> Result<Option<u64>, ParseError> {
+        // the number is built up as a total of completed scales, plus the current group below a
+        // thousand, which is completed by the next scale word
+        let start = self.position();
+        let mut total = 0_u64;
+        let mut group = 0_u64;
+        let mut last_scale = u64::MAX;
+        let mut previous = None;
+
+        while let Some(word) = self.peek() {
+            let is_first = previous.is_none();
+            // nothing can add to `zero`, `a`, or a number in digits
+            let is_closed = matches!(previous, Some(Word::Number(0) | Word::A | Word::Digits(_)));
+
+            let is_valid = match word {
+                Word::Number(0) => is_first,
+                Word::Number(1..=9) => {
+                    !is_closed && group.is_multiple_of(10) && !(10..20).contains(&(group % 100))
+                },
+                Word::Number(_) => !is_closed && group.is_multiple_of(100),
+                Word::Digits(_) => is_first,
+                Word::Hundred => (1..100).contains(&group),
+                Word::Scale(scale) => group > 0 && scale < last_scale,
+                Word::A => is_first,
+                Word::And => {
+                    // `and` within a number, like `one hundred and five`, as opposed to between
+                    // dollars and cents
+                    !is_first
+                        && group.is_multiple_of(100)
+                        && matches!(self.peek_at(1), Some(Word::Number(1..=99)))
+                },
+                _ => break,
+            };
+
+            if !is_valid {
+                if matches!(word, Word::A | Word::And) {
+                    break;
+                }
+
+                return Err(self.error(ParseErrorKind::MisplacedWord));
+            }
+
+            let overflow = || ParseError::new(ParseErrorKind::Overflow, start);
+
+            match word {
+                Word::Number(n) | Word::Digits(n) => group += n,
+                Word::Hundred => group *= 100,
+                Word::Scale(scale) => {
+                    total = group
+                        .checked_mul(scale)
+                        .and_then(|value| value.checked_add(total))
+                        .ok_or_else(overflow)?;
+                    group = 0;
+                    last_scale = scale;
+                },
+                Word::A => group = 1,
+                _ => {},
+            }
+
+            previous = Some(word);
+            self.i += 1;
+        }
+
+        if previous == Some(Word::A) {
+            // `a` on its own only makes sense before a unit, like `a dollar`
+            if !matches!(self.peek(), Some(Word::Dollars | Word::Cents | Word::Grand)) {
+                self.i -= 1;
+                return Err(self.error(ParseErrorKind::MisplacedWord));
+            }
+        }
+
+        match previous {
+            Some(_) => total
+                .checked_add(group)
+                .map(Some)
+                .ok_or_else(|| ParseError::new(ParseErrorKind::Overflow, start)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(cents: i64) -> String {
+        Dollars::from(cents).to_words(WordsStyle::Check)
+    }
+
+    fn spoken(cents: i64) -> String {
+        Dollars::from(cents).to_words(WordsStyle::Spoken)
+    }
+
+    #[test]
+    fn check_style() {
+        assert_eq!(check(0), "Zero and 00/100 dollars");
+        assert_eq!(check(1), "Zero and 01/100 dollars");
+        assert_eq!(check(100), "One and 00/100 dollars");
+        assert_eq!(
+            check(-123456),
+            "Minus one thousand two hundred thirty-four and 56/100 dollars"
+        );
+        assert_eq!(check(100_000_000), "One million and 00/100 dollars");
+        assert_eq!(
+            check(100_100_000),
+            "One million one thousand and 00/100 dollars"
+        );
+    }
+
+    #[test]
+    fn spoken_style() {
+        assert_eq!(spoken(0), "zero dollars");
+        assert_eq!(spoken(1), "one cent");
+        assert_eq!(spoken(100), "one dollar");
+        assert_eq!(spoken(101), "one dollar and one cent");
+        assert_eq!(spoken(-250), "minus two dollars and fifty cents");
+        assert_eq!(
+            spoken(11_911),
+            "one hundred nineteen dollars and eleven cents"
+        );
+        assert_eq!(spoken(7_000_000), "seventy thousand dollars");
+    }
+
+    #[test]
+    fn extremes() {
+        assert_eq!(
+            spoken(i64::MAX),
+            "ninety-two quadrillion two hundred thirty-three trillion seven hundred twenty \
+             billion three hundred sixty-eight million five hundred forty-seven thousand seven \
+             hundred fifty-eight dollars and seven cents"
+        );
+        assert_eq!(
+            check(i64::MIN),
+            "Minus ninety-two quadrillion two hundred thirty-three trillion seven hundred twenty \
+             billion three hundred sixty-eight million five hundred forty-seven thousand seven \
+             hundred fifty-eight and 08/100 dollars"
+        );
+    }
+
+    fn parse(s: &str) -> Result<i64, (ParseErrorKind, usize)> {
+        Dollars::parse_words(s)
+            .map(|value| value.in_cents())
+            .map_err(|error| (error.kind(), error.position()))
+    }
+
+    #[test]
+    fn parsing_round_trip() {
+        for cents in [
+            0,
+            1,
+            100,
+            101,
+            -250,
+            11_911,
+            7_000_000,
+            -123456,
+            i64::MAX,
+            i64::MIN,
+        ] {
+            assert_eq!(parse(&check(cents)), Ok(cents), "{}", check(cents));
+            assert_eq!(parse(&spoken(cents)), Ok(cents), "{}", spoken(cents));
+        }
+    }
+
+    #[test]
+    fn parsing_variations() {
+        assert_eq!(parse("twelve dollars and fifty cents"), Ok(1250));
+        assert_eq!(parse("a buck fifty"), Ok(150));
+        assert_eq!(parse("One thousand two hundred and 56/100"), Ok(120056));
+        assert_eq!(parse("twelve hundred dollars"), Ok(120000));
+        assert_eq!(parse("one hundred and five dollars"), Ok(10500));
+        assert_eq!(parse("12 dollars and 50 cents"), Ok(1250));
+        assert_eq!(parse("five grand"), Ok(500000));
+        assert_eq!(parse("a hundred bucks"), Ok(10000));
+        assert_eq!(parse("NEGATIVE forty-two cents"), Ok(-42));
+        assert_eq!(parse("two million, five hundred thousand"), Ok(250_000_000));
+        assert_eq!(parse("  seventy-five  "), Ok(7500));
+        assert_eq!(parse("twelve dollars and 56/100"), Ok(1256));
+        assert_eq!(parse("1,000 dollars"), Ok(100_000));
+        assert_eq!(parse("1,234,567 dollars and 8 cents"), Ok(123_456_708));
+    }
+
+    #[test]
+    fn parsing_errors() {
+        assert_eq!(parse(""), Err((ParseErrorKind::MissingDigits, 0)));
+        assert_eq!(parse("minus"), Err((ParseErrorKind::MissingDigits, 5)));
+        assert_eq!(
+            parse("ten dollars and"),
+            Err((ParseErrorKind::MissingDigits, 15))
+        );
+        assert_eq!(
+            parse("twelve bananas"),
+            Err((ParseErrorKind::UnknownWord, 7))
+        );
+        assert_eq!(parse("-5 dollars"), Err((ParseErrorKind::UnknownWord, 0)));
+        assert_eq!(
+            parse("1,00 dollars"),
+            Err((ParseErrorKind::InvalidGrouping, 0))
+        );
+        assert_eq!(parse("dollars"), Err((ParseErrorKind::MisplacedWord, 0)));
+        assert_eq!(parse("twenty ten"), Err((ParseErrorKind::MisplacedWord, 7)));
+        assert_eq!(parse("one two"), Err((ParseErrorKind::MisplacedWord, 4)));
+        assert_eq!(
+            parse("one thousand one million"),
+            Err((ParseErrorKind::MisplacedWord, 17))
+        );
+        assert_eq!(parse("a"), Err((ParseErrorKind::MisplacedWord, 0)));
+        assert_eq!(
+            parse("one dollar and 5/100"),
+            Err((ParseErrorKind::BadCentsLength, 15))
+        );
+        assert_eq!(
+            parse("one dollar and one hundred cents"),
+            Err((ParseErrorKind::CentsOutOfRange, 15))
+        );
+        assert_eq!(
+            parse("five dollars five dollars"),
+            Err((ParseErrorKind::TrailingCharacters, 18))
+        );
+        assert_eq!(
+            parse("ninety-three quadrillion dollars"),
+            Err((ParseErrorKind::Overflow, 0))
+        );
+        assert_eq!(
+            parse("99999 quadrillion"),
+            Err((ParseErrorKind::Overflow, 0))
+        );
+    }
+}